@@ -0,0 +1,43 @@
+use std::{env, fs, path::{Path, PathBuf}};
+
+const EMBEDDED_FOLDER_VARIABLE: &str = "RUXY_EMBEDDED_FOLDER";
+const DEFAULT_EMBEDDED_FOLDER: &str = "examples/assets/first";
+const EMBEDDED_SOURCES_FILE: &str = "embedded_sources.rs";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed={}", EMBEDDED_FOLDER_VARIABLE);
+    let mut sources = vec!();
+    if env::var_os("CARGO_FEATURE_EMBEDDED_SOURCES").is_some() {
+        let manifest_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let folder = env::var(EMBEDDED_FOLDER_VARIABLE).unwrap_or_else(|_| DEFAULT_EMBEDDED_FOLDER.to_string());
+        let root_path = manifest_path.join(folder);
+        println!("cargo:rerun-if-changed={}", root_path.display());
+        collect_sources(&root_path, &root_path, &mut sources);
+        sources.sort();
+    }
+
+    let entries = sources.iter()
+        .map(|(relative, absolute)| format!("    ({:?}, include_str!({:?})),\n", relative, absolute))
+        .collect::<String>();
+    let output = format!("&[\n{}]\n", entries);
+    let output_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join(EMBEDDED_SOURCES_FILE);
+    fs::write(output_path, output).unwrap();
+}
+
+fn collect_sources(root_path: &Path, path: &Path, sources: &mut Vec<(String, String)>) {
+    let entries = fs::read_dir(path).unwrap_or_else(|_| panic!("could not read embedded rux folder {:?}", path));
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_sources(root_path, &path, sources);
+        } else if path.extension().is_some_and(|extension| extension == "rux") {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let relative = path.strip_prefix(root_path).unwrap()
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<String>>()
+                .join("/");
+            sources.push((relative, path.display().to_string()));
+        }
+    }
+}
\ No newline at end of file
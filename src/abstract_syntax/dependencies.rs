@@ -0,0 +1,125 @@
+use crate::prelude::*;
+
+pub fn collect_stream_imports(root_location: &SourceLocation, stream: &AbstractSyntaxTokenStream) -> BTreeSet<SourceLocation> {
+    let mut collector = AbstractSyntaxImportCollector::new(root_location);
+    stream.accept(&mut collector, &mut DataContext::default());
+    collector.imports
+}
+
+pub fn resolve_control_location(root_location: &SourceLocation, property: &AbstractSyntaxProperty) -> Option<SourceLocation> {
+    if property.property_type() != &AbstractSyntaxPropertyType::Path {
+        return None;
+    }
+    let relative_location = property.value().get_string_value().ok()?;
+    root_location.to_relative_location(&relative_location).ok()
+}
+
+#[derive(Default, Debug)]
+pub struct AbstractSyntaxDependencyGraph {
+    imports: HashMap<SourceLocation, BTreeSet<SourceLocation>>,
+    importers: HashMap<SourceLocation, BTreeSet<SourceLocation>>
+}
+
+impl AbstractSyntaxDependencyGraph {
+    pub fn update(&mut self, location: &SourceLocation, imports: BTreeSet<SourceLocation>) {
+        self.remove(location);
+        for import in &imports {
+            self.importers.entry(import.clone()).or_default().insert(location.clone());
+        }
+        self.imports.insert(location.clone(), imports);
+    }
+
+    pub fn remove(&mut self, location: &SourceLocation) {
+        for import in self.imports.remove(location).unwrap_or_default() {
+            if let Some(importers) = self.importers.get_mut(&import) {
+                importers.remove(location);
+                if importers.is_empty() {
+                    self.importers.remove(&import);
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.imports.clear();
+        self.importers.clear();
+    }
+
+    pub fn imports_of(&self, location: &SourceLocation) -> Vec<SourceLocation> {
+        self.imports.get(location).map(|imports| imports.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    pub fn importers_of(&self, location: &SourceLocation) -> Vec<SourceLocation> {
+        self.importers.get(location).map(|importers| importers.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    pub fn dependents_of<'a>(&self, locations: impl IntoIterator<Item = &'a SourceLocation>) -> BTreeSet<SourceLocation> {
+        let mut dependents = BTreeSet::new();
+        let mut pending = locations.into_iter().cloned().collect::<Vec<SourceLocation>>();
+        while let Some(location) = pending.pop() {
+            if dependents.insert(location.clone()) {
+                pending.extend(self.importers_of(&location));
+            }
+        }
+        dependents
+    }
+
+    pub fn dependencies_of(&self, location: &SourceLocation) -> BTreeSet<SourceLocation> {
+        let mut dependencies = BTreeSet::new();
+        let mut pending = vec!(location.clone());
+        while let Some(location) = pending.pop() {
+            if dependencies.insert(location.clone()) {
+                pending.extend(self.imports_of(&location));
+            }
+        }
+        dependencies
+    }
+}
+
+struct AbstractSyntaxImportCollector<'a> {
+    root_location: &'a SourceLocation,
+    imports: BTreeSet<SourceLocation>,
+    positions: Vec<usize>,
+    control: AbstractSyntaxControlType
+}
+
+impl<'a> AbstractSyntaxImportCollector<'a> {
+    fn new(root_location: &'a SourceLocation) -> Self {
+        Self {
+            root_location,
+            imports: BTreeSet::new(),
+            positions: vec!(),
+            control: AbstractSyntaxControlType::Unknown
+        }
+    }
+}
+
+impl<'a> AbstractSyntaxTokenStreamVisitor for AbstractSyntaxImportCollector<'a> {
+    fn push_last_node_position(&mut self, position: usize) {
+        self.positions.push(position);
+    }
+
+    fn pop_last_node_position(&mut self) -> Option<usize> {
+        self.positions.pop()
+    }
+
+    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, _span: &SourceSpan, _context: &mut DataContext) {
+        self.control = *node_type;
+    }
+
+    fn property(&mut self, property: &AbstractSyntaxProperty, _span: &SourceSpan, _context: &mut DataContext) {
+        if self.control == AbstractSyntaxControlType::Control {
+            if let Some(location) = resolve_control_location(self.root_location, property) {
+                self.imports.insert(location);
+            }
+        }
+    }
+
+    fn end_node(&mut self, _node_type: &AbstractSyntaxControlType, _span: &SourceSpan, _context: &mut DataContext) -> EndNodeAction {
+        self.control = AbstractSyntaxControlType::Unknown;
+        EndNodeAction::Continue
+    }
+
+    fn token_error(&mut self, _error: &AbstractSyntaxTokenError, _span: &SourceSpan) {
+    }
+}
\ No newline at end of file
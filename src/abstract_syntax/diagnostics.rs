@@ -0,0 +1,235 @@
+use crate::prelude::*;
+
+pub fn collect_stream_diagnostics(
+    root_location: &SourceLocation,
+    stream: &AbstractSyntaxTokenStream,
+    stream_lookup: &AbstractSyntaxTokenStreamLookup,
+    context: &DataContext
+) -> Diagnostics {
+    let mut collector = AbstractSyntaxDiagnosticsCollector::new(root_location, stream_lookup, context);
+    stream.accept(&mut collector, &mut DataContext::default());
+    collector.diagnostics.extend(stream.warnings().clone());
+    collector.diagnostics
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Diagnostic {
+    severity: DiagnosticSeverity,
+    span: Option<SourceSpan>,
+    message: String
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Error,
+            span: None,
+            message: message.into()
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Warning,
+            span: None,
+            message: message.into()
+        }
+    }
+
+    pub fn token_error(error: &AbstractSyntaxTokenError, span: &SourceSpan) -> Self {
+        Self::error(error.to_string()).with_span(error.span().unwrap_or(span))
+    }
+
+    pub fn with_span(mut self, span: &SourceSpan) -> Self {
+        self.span = Some(span.clone());
+        self
+    }
+
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.severity
+    }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.span {
+            Some(span) => write!(f, "{}: {}", span, self.message),
+            None => write!(f, "{}", self.message)
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Diagnostics(Vec<Diagnostic>);
+
+impl Diagnostics {
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        if !self.0.contains(&diagnostic) {
+            self.0.push(diagnostic);
+        }
+    }
+
+    pub fn extend(&mut self, diagnostics: Diagnostics) {
+        for diagnostic in diagnostics.0 {
+            self.push(diagnostic);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.0.iter()
+    }
+
+    pub fn contains(&self, diagnostic: &Diagnostic) -> bool {
+        self.0.contains(diagnostic)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.0.iter().any(|diagnostic| diagnostic.severity() == DiagnosticSeverity::Error)
+    }
+}
+
+impl From<Diagnostic> for Diagnostics {
+    fn from(from: Diagnostic) -> Self {
+        Self(vec!(from))
+    }
+}
+
+struct AbstractSyntaxDiagnosticsCollector<'a> {
+    root_location: &'a SourceLocation,
+    stream_lookup: &'a AbstractSyntaxTokenStreamLookup,
+    context: &'a DataContext,
+    diagnostics: Diagnostics,
+    positions: Vec<usize>,
+    control: AbstractSyntaxControlType
+}
+
+impl<'a> AbstractSyntaxDiagnosticsCollector<'a> {
+    fn new(
+        root_location: &'a SourceLocation,
+        stream_lookup: &'a AbstractSyntaxTokenStreamLookup,
+        context: &'a DataContext
+    ) -> Self {
+        Self {
+            root_location,
+            stream_lookup,
+            context,
+            diagnostics: Diagnostics::default(),
+            positions: vec!(),
+            control: AbstractSyntaxControlType::Unknown
+        }
+    }
+
+    fn check_value_functions(&mut self, value: &AbstractSyntaxPropertyValue, span: &SourceSpan) {
+        match value {
+            AbstractSyntaxPropertyValue::Function(function) => {
+                let context = self.context;
+                self.check_function(function, "action", context.actions().function_names(), span);
+            },
+            AbstractSyntaxPropertyValue::Selector(function) |
+            AbstractSyntaxPropertyValue::FunctionVariable(_, function) => {
+                let context = self.context;
+                self.check_function(function, "selector", context.selectors().function_names(), span);
+            },
+            AbstractSyntaxPropertyValue::Array(values) |
+            AbstractSyntaxPropertyValue::ArrayVariable(_, values) => {
+                for value in values {
+                    self.check_value_functions(value, span);
+                }
+            },
+            AbstractSyntaxPropertyValue::Expression(expression) |
+            AbstractSyntaxPropertyValue::ExpressionVariable(_, expression) => self.check_expression_functions(expression, span),
+            _ => {}
+        }
+    }
+
+    fn check_expression_functions(&mut self, expression: &Expression, span: &SourceSpan) {
+        match expression {
+            Expression::Value(value) => self.check_value_functions(value, span),
+            Expression::Unary(_, operand) => self.check_expression_functions(operand, span),
+            Expression::Binary(left, _, right) => {
+                self.check_expression_functions(left, span);
+                self.check_expression_functions(right, span);
+            },
+            Expression::Conditional(condition, then, otherwise) => {
+                self.check_expression_functions(condition, span);
+                self.check_expression_functions(then, span);
+                self.check_expression_functions(otherwise, span);
+            }
+        }
+    }
+
+    fn check_function<'b>(
+        &mut self,
+        function: &Function,
+        kind: &str,
+        known: impl Iterator<Item = &'b str> + Clone,
+        span: &SourceSpan
+    ) {
+        if !known.clone().any(|name| name == function.name()) {
+            let message = format!("unknown {} function {:?}", kind, function.name());
+            let suggestion = closest_match(function.name(), known);
+            self.diagnostics.push(Diagnostic::warning(with_suggestion(message, suggestion)).with_span(span));
+        }
+        for argument in function.arguments() {
+            self.check_value_functions(argument, span);
+        }
+    }
+}
+
+impl<'a> AbstractSyntaxTokenStreamVisitor for AbstractSyntaxDiagnosticsCollector<'a> {
+    fn push_last_node_position(&mut self, position: usize) {
+        self.positions.push(position);
+    }
+
+    fn pop_last_node_position(&mut self) -> Option<usize> {
+        self.positions.pop()
+    }
+
+    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, _span: &SourceSpan, _context: &mut DataContext) {
+        self.control = *node_type;
+    }
+
+    fn property(&mut self, property: &AbstractSyntaxProperty, span: &SourceSpan, _context: &mut DataContext) {
+        self.check_value_functions(property.value(), span);
+        if self.control != AbstractSyntaxControlType::Control || property.property_type() != &AbstractSyntaxPropertyType::Path {
+            return;
+        }
+        match resolve_control_location(self.root_location, property) {
+            Some(location) if self.stream_lookup.contains_key(&location) => {},
+            Some(location) => self.diagnostics.push(Diagnostic::error(format!("imported file {} was not found", location)).with_span(span)),
+            None => self.diagnostics.push(Diagnostic::error(format!("import path {:?} could not be resolved", property.value())).with_span(span))
+        }
+    }
+
+    fn end_node(&mut self, _node_type: &AbstractSyntaxControlType, _span: &SourceSpan, _context: &mut DataContext) -> EndNodeAction {
+        self.control = AbstractSyntaxControlType::Unknown;
+        EndNodeAction::Continue
+    }
+
+    fn token_error(&mut self, error: &AbstractSyntaxTokenError, span: &SourceSpan) {
+        self.diagnostics.push(Diagnostic::token_error(error, span));
+    }
+}
\ No newline at end of file
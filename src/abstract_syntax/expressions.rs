@@ -0,0 +1,279 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub enum Expression {
+    Value(AbstractSyntaxPropertyValue),
+    Unary(CodeOperator, Box<Expression>),
+    Binary(Box<Expression>, CodeOperator, Box<Expression>),
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>)
+}
+
+impl Expression {
+    pub fn parse(value: &[CodeTokenResult]) -> Result<Expression, AbstractSyntaxTokenError> {
+        let mut tokens = vec!();
+        for result in value {
+            match result {
+                Ok(token) => tokens.push(token),
+                Err(err) => return Err(AbstractSyntaxTokenError::CodeTokenError(err.clone()))
+            }
+        }
+        let mut parser = ExpressionParser { tokens, position: 0 };
+        let expression = parser.parse_conditional()?;
+        if let Some(token) = parser.peek() {
+            return Err(unexpected_code_token(Some(token)));
+        }
+        Ok(expression)
+    }
+
+    pub fn into_value(self) -> AbstractSyntaxPropertyValue {
+        match self {
+            Self::Value(value) => value,
+            expression => AbstractSyntaxPropertyValue::Expression(Box::new(expression))
+        }
+    }
+}
+
+struct ExpressionParser<'a> {
+    tokens: Vec<&'a CodeTokenPropertyValue>,
+    position: usize
+}
+
+impl<'a> ExpressionParser<'a> {
+    fn peek(&self) -> Option<&'a CodeTokenPropertyValue> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a CodeTokenPropertyValue> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: CodeTokenPropertyValue) -> Result<(), AbstractSyntaxTokenError> {
+        match self.next() {
+            Some(token) if *token == expected => Ok(()),
+            token => Err(unexpected_code_token(token))
+        }
+    }
+
+    fn peek_operator(&self, operators: &[CodeOperator]) -> Option<CodeOperator> {
+        match self.peek() {
+            Some(CodeTokenPropertyValue::Operator(operator)) if operators.contains(operator) => Some(*operator),
+            _ => None
+        }
+    }
+
+    fn parse_conditional(&mut self) -> Result<Expression, AbstractSyntaxTokenError> {
+        let condition = self.parse_binary(0)?;
+        if self.peek() != Some(&CodeTokenPropertyValue::Conditional) {
+            return Ok(condition);
+        }
+        self.next();
+        let when_true = self.parse_conditional()?;
+        self.expect(CodeTokenPropertyValue::ConditionalElse)?;
+        let when_false = self.parse_conditional()?;
+        Ok(Expression::Conditional(Box::new(condition), Box::new(when_true), Box::new(when_false)))
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<Expression, AbstractSyntaxTokenError> {
+        if level == BINARY_OPERATOR_LEVELS.len() {
+            return self.parse_unary();
+        }
+        let mut left = self.parse_binary(level + 1)?;
+        while let Some(operator) = self.peek_operator(BINARY_OPERATOR_LEVELS[level]) {
+            self.next();
+            let right = self.parse_binary(level + 1)?;
+            left = Expression::Binary(Box::new(left), operator, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, AbstractSyntaxTokenError> {
+        if let Some(operator) = self.peek_operator(&[CodeOperator::Not, CodeOperator::Negate, CodeOperator::Subtract]) {
+            self.next();
+            let operand = self.parse_unary()?;
+            let operator = if operator == CodeOperator::Not { CodeOperator::Not } else { CodeOperator::Negate };
+            return Ok(Expression::Unary(operator, Box::new(operand)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, AbstractSyntaxTokenError> {
+        match self.next() {
+            Some(CodeTokenPropertyValue::PropertyValue(value)) => Ok(Expression::Value(parse_operand(value)?)),
+            Some(CodeTokenPropertyValue::StartFunction(name)) => {
+                let function = Function::new(name.clone(), self.parse_arguments()?);
+                Ok(Expression::Value(AbstractSyntaxPropertyValue::Selector(function)))
+            },
+            Some(CodeTokenPropertyValue::StartGroup) => {
+                let expression = self.parse_conditional()?;
+                self.expect(CodeTokenPropertyValue::EndGroup)?;
+                Ok(expression)
+            },
+            token => Err(unexpected_code_token(token))
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<AbstractSyntaxPropertyValue>, AbstractSyntaxTokenError> {
+        let mut arguments = vec!();
+        if self.peek() == Some(&CodeTokenPropertyValue::EndFunction) {
+            self.next();
+            return Ok(arguments);
+        }
+        loop {
+            arguments.push(self.parse_conditional()?.into_value());
+            match self.next() {
+                Some(CodeTokenPropertyValue::ArgumentSeparator) => continue,
+                Some(CodeTokenPropertyValue::EndFunction) => return Ok(arguments),
+                token => return Err(unexpected_code_token(token))
+            }
+        }
+    }
+}
+
+fn unexpected_code_token(token: Option<&CodeTokenPropertyValue>) -> AbstractSyntaxTokenError {
+    match token {
+        Some(token) => AbstractSyntaxTokenError::UnexpectedCodeToken(format!("{:?}", token)),
+        None => AbstractSyntaxTokenError::UnexpectedEndOfCode
+    }
+}
+
+const BINARY_OPERATOR_LEVELS: [&[CodeOperator]; 6] = [
+    &[CodeOperator::Or],
+    &[CodeOperator::And],
+    &[CodeOperator::Equal, CodeOperator::NotEqual],
+    &[CodeOperator::Less, CodeOperator::LessOrEqual, CodeOperator::Greater, CodeOperator::GreaterOrEqual],
+    &[CodeOperator::Add, CodeOperator::Subtract],
+    &[CodeOperator::Multiply, CodeOperator::Divide, CodeOperator::Remainder]
+];
+
+fn parse_operand(value: &SourceTokenPropertyValue) -> Result<AbstractSyntaxPropertyValue, AbstractSyntaxTokenError> {
+    match value {
+        SourceTokenPropertyValue::USize(value) => Ok(AbstractSyntaxPropertyValue::USize(*value)),
+        SourceTokenPropertyValue::Int(value) => Ok(AbstractSyntaxPropertyValue::Int(*value)),
+        SourceTokenPropertyValue::Variable(value) => Ok(AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)),
+        value => value.try_into()
+    }
+}
+
+pub fn apply_unary_operator(
+    operator: CodeOperator,
+    operand: &AbstractSyntaxPropertyValue
+) -> Result<AbstractSyntaxPropertyValue, AbstractSyntaxPropertyValueError> {
+    match (operator, operand) {
+        (CodeOperator::Not, AbstractSyntaxPropertyValue::Bool(value)) => Ok(AbstractSyntaxPropertyValue::Bool(!value)),
+        (CodeOperator::Negate, AbstractSyntaxPropertyValue::Float(value)) => Ok(AbstractSyntaxPropertyValue::Float(-value)),
+        (CodeOperator::Negate, AbstractSyntaxPropertyValue::USize(value)) => Ok(AbstractSyntaxPropertyValue::Int(-(*value as i128))),
+        (CodeOperator::Negate, AbstractSyntaxPropertyValue::Int(value)) if *value != i128::MIN => Ok(AbstractSyntaxPropertyValue::Int(-value)),
+        _ => Err(AbstractSyntaxPropertyValueError::OperatorNotSupported(operator, operand.clone()))
+    }
+}
+
+pub fn apply_binary_operator(
+    operator: CodeOperator,
+    left: &AbstractSyntaxPropertyValue,
+    right: &AbstractSyntaxPropertyValue
+) -> Result<AbstractSyntaxPropertyValue, AbstractSyntaxPropertyValueError> {
+    let result = match (operator, left, right) {
+        (CodeOperator::Add, AbstractSyntaxPropertyValue::String(_), _) |
+        (CodeOperator::Add, _, AbstractSyntaxPropertyValue::String(_)) =>
+            Some(AbstractSyntaxPropertyValue::String(format!("{}{}", left.get_display_value()?, right.get_display_value()?))),
+        (CodeOperator::Equal, _, _) => Some(AbstractSyntaxPropertyValue::Bool(values_equal(left, right))),
+        (CodeOperator::NotEqual, _, _) => Some(AbstractSyntaxPropertyValue::Bool(!values_equal(left, right))),
+        (CodeOperator::And, AbstractSyntaxPropertyValue::Bool(left_value), AbstractSyntaxPropertyValue::Bool(right_value)) =>
+            Some(AbstractSyntaxPropertyValue::Bool(*left_value && *right_value)),
+        (CodeOperator::Or, AbstractSyntaxPropertyValue::Bool(left_value), AbstractSyntaxPropertyValue::Bool(right_value)) =>
+            Some(AbstractSyntaxPropertyValue::Bool(*left_value || *right_value)),
+        (_, AbstractSyntaxPropertyValue::USize(left_value), AbstractSyntaxPropertyValue::USize(right_value)) =>
+            apply_usize_operator(operator, *left_value, *right_value),
+        (_, AbstractSyntaxPropertyValue::String(left_value), AbstractSyntaxPropertyValue::String(right_value)) =>
+            apply_ordering_operator(operator, left_value.cmp(right_value)),
+        _ => match (integer_value(left), integer_value(right)) {
+            (Some(left_value), Some(right_value)) => apply_int_operator(operator, left_value, right_value),
+            _ => match (number_value(left), number_value(right)) {
+                (Some(left_value), Some(right_value)) => apply_float_operator(operator, left_value, right_value),
+                (None, _) => return Err(AbstractSyntaxPropertyValueError::OperatorNotSupported(operator, left.clone())),
+                (_, None) => return Err(AbstractSyntaxPropertyValueError::OperatorNotSupported(operator, right.clone()))
+            }
+        }
+    };
+    result.ok_or_else(|| AbstractSyntaxPropertyValueError::OperatorNotSupported(operator, right.clone()))
+}
+
+fn integer_value(value: &AbstractSyntaxPropertyValue) -> Option<i128> {
+    match value {
+        AbstractSyntaxPropertyValue::USize(value) => Some(*value as i128),
+        AbstractSyntaxPropertyValue::Int(value) => Some(*value),
+        _ => None
+    }
+}
+
+fn number_value(value: &AbstractSyntaxPropertyValue) -> Option<f32> {
+    match value {
+        AbstractSyntaxPropertyValue::Float(value) => Some(*value),
+        AbstractSyntaxPropertyValue::USize(value) => Some(*value as f32),
+        AbstractSyntaxPropertyValue::Int(value) => Some(*value as f32),
+        _ => None
+    }
+}
+
+fn values_equal(left: &AbstractSyntaxPropertyValue, right: &AbstractSyntaxPropertyValue) -> bool {
+    match (left, right) {
+        (AbstractSyntaxPropertyValue::String(left), AbstractSyntaxPropertyValue::String(right)) => left == right,
+        (AbstractSyntaxPropertyValue::Bool(left), AbstractSyntaxPropertyValue::Bool(right)) => left == right,
+        _ => match (integer_value(left), integer_value(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => match (number_value(left), number_value(right)) {
+                (Some(left), Some(right)) => left == right,
+                _ => false
+            }
+        }
+    }
+}
+
+fn apply_ordering_operator(operator: CodeOperator, ordering: std::cmp::Ordering) -> Option<AbstractSyntaxPropertyValue> {
+    let result = match operator {
+        CodeOperator::Less => ordering.is_lt(),
+        CodeOperator::LessOrEqual => ordering.is_le(),
+        CodeOperator::Greater => ordering.is_gt(),
+        CodeOperator::GreaterOrEqual => ordering.is_ge(),
+        _ => return None
+    };
+    Some(AbstractSyntaxPropertyValue::Bool(result))
+}
+
+fn apply_usize_operator(operator: CodeOperator, left: usize, right: usize) -> Option<AbstractSyntaxPropertyValue> {
+    match operator {
+        CodeOperator::Add => left.checked_add(right).map(AbstractSyntaxPropertyValue::USize),
+        CodeOperator::Subtract => match left.checked_sub(right) {
+            Some(value) => Some(AbstractSyntaxPropertyValue::USize(value)),
+            None => Some(AbstractSyntaxPropertyValue::Int(left as i128 - right as i128))
+        },
+        CodeOperator::Multiply => left.checked_mul(right).map(AbstractSyntaxPropertyValue::USize),
+        CodeOperator::Divide => left.checked_div(right).map(AbstractSyntaxPropertyValue::USize),
+        CodeOperator::Remainder => left.checked_rem(right).map(AbstractSyntaxPropertyValue::USize),
+        _ => apply_ordering_operator(operator, left.cmp(&right))
+    }
+}
+
+fn apply_int_operator(operator: CodeOperator, left: i128, right: i128) -> Option<AbstractSyntaxPropertyValue> {
+    match operator {
+        CodeOperator::Add => left.checked_add(right).map(AbstractSyntaxPropertyValue::Int),
+        CodeOperator::Subtract => left.checked_sub(right).map(AbstractSyntaxPropertyValue::Int),
+        CodeOperator::Multiply => left.checked_mul(right).map(AbstractSyntaxPropertyValue::Int),
+        CodeOperator::Divide => left.checked_div(right).map(AbstractSyntaxPropertyValue::Int),
+        CodeOperator::Remainder => left.checked_rem(right).map(AbstractSyntaxPropertyValue::Int),
+        _ => apply_ordering_operator(operator, left.cmp(&right))
+    }
+}
+
+fn apply_float_operator(operator: CodeOperator, left: f32, right: f32) -> Option<AbstractSyntaxPropertyValue> {
+    match operator {
+        CodeOperator::Add => Some(AbstractSyntaxPropertyValue::Float(left + right)),
+        CodeOperator::Subtract => Some(AbstractSyntaxPropertyValue::Float(left - right)),
+        CodeOperator::Multiply => Some(AbstractSyntaxPropertyValue::Float(left * right)),
+        CodeOperator::Divide if right != 0.0 => Some(AbstractSyntaxPropertyValue::Float(left / right)),
+        CodeOperator::Remainder if right != 0.0 => Some(AbstractSyntaxPropertyValue::Float(left % right)),
+        _ => apply_ordering_operator(operator, left.partial_cmp(&right)?)
+    }
+}
\ No newline at end of file
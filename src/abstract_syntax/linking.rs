@@ -1,31 +1,90 @@
-
 use crate::prelude::*;
 
 
-pub fn link_streams(
-    root_location: SourceLocation,
-    location: SourceLocation,
-    stream_lookup: &AbstractSyntaxTokenStreamLookup
-) -> AbstractSyntaxTokenStream {
-    let stream = stream_lookup.get(&location).unwrap(); 
-    let mut linker = AbstractSyntaxStreamLinker::new(root_location, stream_lookup);
+pub fn relink_streams(
+    root_location: &SourceLocation,
+    locations: BTreeSet<SourceLocation>,
+    stream_lookup: &AbstractSyntaxTokenStreamLookup,
+    dependencies: &AbstractSyntaxDependencyGraph,
+    linked_lookup: &mut AbstractSyntaxTokenStreamLookup
+) -> Vec<SourceLocation> {
+    let mut relinker = AbstractSyntaxStreamRelinker {
+        root_location,
+        stream_lookup,
+        dependencies,
+        linked_lookup,
+        pending: locations,
+        linking: vec!(),
+        relinked: vec!()
+    };
+    while let Some(location) = relinker.pending.iter().next().cloned() {
+        relinker.relink_stream(location);
+    }
+    relinker.relinked
+}
+
+pub fn link_stream(
+    root_location: &SourceLocation,
+    location: &SourceLocation,
+    stream_lookup: &AbstractSyntaxTokenStreamLookup,
+    linked_lookup: &AbstractSyntaxTokenStreamLookup,
+    excluded: &[SourceLocation]
+) -> Option<AbstractSyntaxTokenStream> {
+    let stream = stream_lookup.get(location)?; 
+    let mut linker = AbstractSyntaxStreamLinker::new(root_location, linked_lookup, excluded);
     stream.accept(&mut linker, &mut DataContext::default());
-    linker.linked_stream()
+    Some(linker.linked_stream())
+}
+
+struct AbstractSyntaxStreamRelinker<'a> {
+    root_location: &'a SourceLocation,
+    stream_lookup: &'a AbstractSyntaxTokenStreamLookup,
+    dependencies: &'a AbstractSyntaxDependencyGraph,
+    linked_lookup: &'a mut AbstractSyntaxTokenStreamLookup,
+    pending: BTreeSet<SourceLocation>,
+    linking: Vec<SourceLocation>,
+    relinked: Vec<SourceLocation>
 }
 
+impl<'a> AbstractSyntaxStreamRelinker<'a> {
+    fn relink_stream(&mut self, location: SourceLocation) {
+        if !self.pending.remove(&location) {
+            return;
+        }
+        self.linking.push(location.clone());
+        for import in self.dependencies.imports_of(&location) {
+            if !self.linking.contains(&import) {
+                self.relink_stream(import);
+            }
+        }
+        self.linking.pop();
+        let ancestors = self.linking.iter().cloned().chain(Some(location.clone())).collect::<Vec<SourceLocation>>();
+        match link_stream(self.root_location, &location, self.stream_lookup, self.linked_lookup, &ancestors) {
+            Some(linked_stream) => {
+                self.linked_lookup.insert(location.clone(), linked_stream);
+                self.relinked.push(location);
+            },
+            None => {
+                self.linked_lookup.remove(&location);
+            }
+        }
+    }
+}
 
 pub struct AbstractSyntaxStreamLinker<'a> {
-    stream_lookup: &'a AbstractSyntaxTokenStreamLookup,
+    linked_lookup: &'a AbstractSyntaxTokenStreamLookup,
+    excluded: &'a [SourceLocation],
     linked_stream: AbstractSyntaxTokenStream,
     positions: Vec<usize>,
-    root_location: SourceLocation,
+    root_location: &'a SourceLocation,
     control: AbstractSyntaxControlType
 }
 
 impl<'a> AbstractSyntaxStreamLinker<'a> {
-    fn new(root_location: SourceLocation, stream_lookup: &'a AbstractSyntaxTokenStreamLookup) -> Self {
+    fn new(root_location: &'a SourceLocation, linked_lookup: &'a AbstractSyntaxTokenStreamLookup, excluded: &'a [SourceLocation]) -> Self {
         Self {
-            stream_lookup,
+            linked_lookup,
+            excluded,
             linked_stream: AbstractSyntaxTokenStream::default(),
             positions: vec!(),
             root_location,
@@ -46,40 +105,40 @@ impl<'a> AbstractSyntaxTokenStreamVisitor for AbstractSyntaxStreamLinker<'a> {
         self.positions.pop()
     }
 
-    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, _context: &mut DataContext) {
+    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, span: &SourceSpan, _context: &mut DataContext) {
         match node_type {
             AbstractSyntaxControlType::Empty => {},
             AbstractSyntaxControlType::Control => {},
-            other => self.linked_stream.start_node(*other),
+            other => self.linked_stream.start_node(*other, span),
         }
         self.control = *node_type;
     }
 
-    fn property(&mut self, property: &AbstractSyntaxProperty, _context: &mut DataContext) {
+    fn property(&mut self, property: &AbstractSyntaxProperty, span: &SourceSpan, _context: &mut DataContext) {
         match self.control {
             AbstractSyntaxControlType::Empty => {},
             AbstractSyntaxControlType::Control => {
-                if property.property_type() == &AbstractSyntaxPropertyType::Path {
-                    let relative_location = property.value().get_string_value().unwrap();
-                    let control_location = self.root_location.to_relative_location(&relative_location).unwrap();
-                    let mut control_stream = link_streams(self.root_location.clone(), control_location, &self.stream_lookup);
-                    self.linked_stream.append_stream(&mut control_stream)
+                let control_stream = resolve_control_location(self.root_location, property)
+                    .filter(|control_location| !self.excluded.contains(control_location))
+                    .and_then(|control_location| self.linked_lookup.get(&control_location));
+                if let Some(control_stream) = control_stream {
+                    self.linked_stream.append_stream(&mut control_stream.clone())
                 }
             },
-            _=> self.linked_stream.property(property.clone())
+            _=> self.linked_stream.property(property.clone(), span)
         }
     }
 
-    fn end_node(&mut self, node_type: &AbstractSyntaxControlType, _context: &mut DataContext) -> EndNodeAction {
+    fn end_node(&mut self, node_type: &AbstractSyntaxControlType, span: &SourceSpan, _context: &mut DataContext) -> EndNodeAction {
         match node_type {
             AbstractSyntaxControlType::Empty => {},
             AbstractSyntaxControlType::Control => {},
-            other => self.linked_stream.end_node(*other),
+            other => self.linked_stream.end_node(*other, span),
         }
         self.control = AbstractSyntaxControlType::Unknown;
         EndNodeAction::Continue
     }
 
-    fn token_error(&mut self, _error: &AbstractSyntaxTokenError) {
+    fn token_error(&mut self, _error: &AbstractSyntaxTokenError, _span: &SourceSpan) {
     }
 }
\ No newline at end of file
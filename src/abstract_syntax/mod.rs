@@ -1,10 +1,19 @@
 mod properties;
 mod types;
+mod expressions;
 mod linking;
+mod dependencies;
+mod diagnostics;
+mod suggestions;
+mod tests;
 
 pub use properties::*;
 pub use types::*;
+pub use expressions::*;
 pub use linking::*;
+pub use dependencies::*;
+pub use diagnostics::*;
+pub use suggestions::*;
 
 use crate::prelude::*;
 
@@ -13,30 +22,129 @@ pub struct AbstractSyntax {
     graph: AbstractSyntaxGraph,
     linked_stream: AbstractSyntaxTokenStream,
     stream_lookup: AbstractSyntaxTokenStreamLookup,
+    linked_lookup: AbstractSyntaxTokenStreamLookup,
+    dependencies: AbstractSyntaxDependencyGraph,
+    root_names: BTreeMap<SourceLocation, String>,
     root_location: Option<SourceLocation>,
+    source_diagnostics: Diagnostics,
+    root_diagnostics: Diagnostics,
+    link_diagnostics: Diagnostics,
+    graph_diagnostics: Diagnostics,
 }
 
 impl AbstractSyntax {
     pub fn build(&mut self, changes: &SourceChanges, source_files: &mut SourceFiles, context: &mut DataContext) {
-        if !changes.is_empty() {
-            self.build_and_link_streams(changes, source_files);
+        let active_root = context.active_root().unwrap_or(DEFAULT_ROOT_NAME).to_string();
+        self.source_diagnostics = source_files.diagnostics().clone();
+        if !changes.is_empty() || self.select_root_location(&active_root) != self.root_location {
+            self.build_and_link_streams(changes, source_files, &active_root, context);
         }
-        self.graph = build_graph(context, &mut self.linked_stream);
+        self.root_diagnostics = self.collect_root_diagnostics(&active_root);
+        match build_graph(context, &mut self.linked_stream) {
+            Ok(graph) => {
+                self.graph = graph;
+                self.graph_diagnostics = Diagnostics::default();
+            },
+            Err(diagnostics) => self.graph_diagnostics = diagnostics
+        }
+    }
+
+    fn build_and_link_streams(
+        &mut self,
+        changes: &SourceChanges,
+        source_files: &mut SourceFiles,
+        active_root: &str,
+        context: &DataContext
+    ) {
+        build_streams(changes, source_files, context.controls(), &mut self.stream_lookup);
+        for location in changes.iter() {
+            match self.stream_lookup.get(location).and_then(|stream| stream.root_name()) {
+                Some(root_name) => self.root_names.insert(location.clone(), root_name),
+                None => self.root_names.remove(location)
+            };
+        }
+        let root_location = match self.select_root_location(active_root) {
+            Some(root_location) => root_location,
+            None => {
+                self.root_location = None;
+                self.link_diagnostics = Diagnostic::error("no root found").into();
+                return;
+            }
+        };
+
+        let changed_locations = match self.root_location.as_ref() == Some(&root_location) {
+            true => changes.iter().cloned().collect::<BTreeSet<SourceLocation>>(),
+            false => self.reset_links(root_location.clone())
+        };
+        for location in &changed_locations {
+            match self.stream_lookup.get(location) {
+                Some(stream) => self.dependencies.update(location, collect_stream_imports(&root_location, stream)),
+                None => self.dependencies.remove(location)
+            }
+            debug!("{:?} is imported by {:?}", location, self.dependencies.importers_of(location));
+        }
+
+        let dependents = self.dependencies.dependents_of(&changed_locations);
+        let relinked = relink_streams(&root_location, dependents, &self.stream_lookup, &self.dependencies, &mut self.linked_lookup);
+        debug!("relinked {:?}", relinked);
 
+        self.link_diagnostics = self.collect_link_diagnostics(&root_location, context);
+        if !self.link_diagnostics.has_errors() {
+            self.linked_stream = self.linked_lookup.get(&root_location).cloned().unwrap_or_default();
+        }
     }
 
-    fn build_and_link_streams(&mut self, changes: &SourceChanges, source_files: &mut SourceFiles) {
-        if let Some(root_location) = build_streams(changes, source_files, &mut self.stream_lookup) {
-            self.root_location = Some(root_location);
+    fn collect_link_diagnostics(&self, root_location: &SourceLocation, context: &DataContext) -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+        for location in self.dependencies.dependencies_of(root_location) {
+            if let Some(stream) = self.stream_lookup.get(&location) {
+                diagnostics.extend(collect_stream_diagnostics(root_location, stream, &self.stream_lookup, context));
+            }
         }
-        if let Some(root_location) = &self.root_location {
-            self.linked_stream = link_streams(root_location.clone(), root_location.clone(), &self.stream_lookup);
-        } else{
-            panic!("No root found")
+        diagnostics
+    }
+
+    fn collect_root_diagnostics(&self, active_root: &str) -> Diagnostics {
+        let mut root_locations: BTreeMap<&str, Vec<&SourceLocation>> = BTreeMap::new();
+        for (location, root_name) in &self.root_names {
+            root_locations.entry(root_name.as_str()).or_default().push(location);
         }
-    }    
+        let mut diagnostics = Diagnostics::default();
+        if let (false, Some(location)) = (root_locations.contains_key(active_root), &self.root_location) {
+            let message = format!("root {:?} not found, using {}", active_root, location);
+            let suggestion = closest_match(active_root, root_locations.keys().copied());
+            diagnostics.push(Diagnostic::warning(with_suggestion(message, suggestion)));
+        }
+        for (root_name, locations) in root_locations.iter().filter(|(_, locations)| locations.len() > 1) {
+            let declared_in = locations.iter().map(|location| location.to_string()).collect::<Vec<String>>().join(", ");
+            diagnostics.push(Diagnostic::warning(format!("root {:?} is declared in {}, using {}", root_name, declared_in, locations[0])));
+        }
+        diagnostics
+    }
+
+    fn select_root_location(&self, active_root: &str) -> Option<SourceLocation> {
+        let named_root = |name: &str| self.root_names.iter().find(|(_, root_name)| root_name.as_str() == name);
+        named_root(active_root)
+            .or_else(|| named_root(DEFAULT_ROOT_NAME))
+            .or_else(|| self.root_names.iter().next())
+            .map(|(location, _)| location.clone())
+    }
+
+    fn reset_links(&mut self, root_location: SourceLocation) -> BTreeSet<SourceLocation> {
+        self.root_location = Some(root_location);
+        self.dependencies.clear();
+        self.linked_lookup.clear();
+        self.stream_lookup.keys().cloned().collect()
+    }
 
     pub fn graph(&self) ->  &AbstractSyntaxGraph {
         &self.graph
     }
+
+    pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.source_diagnostics.iter()
+            .chain(self.root_diagnostics.iter())
+            .chain(self.link_diagnostics.iter())
+            .chain(self.graph_diagnostics.iter())
+    }
 }
\ No newline at end of file
@@ -6,22 +6,68 @@ pub enum AbstractSyntaxPropertyValue {
     String(String),
     Bool(bool),
     Float(f32),
-    FloatRange(FloatRange),
+    Size(Size),
+    SizeRange(SizeRange),
     Colour(Colour), 
     Function(Function),
+    Selector(Function),
     USize(usize),
-    USizeRangeVariable(String, USizeRange),
+    Int(i128),
+    Array(Vec<AbstractSyntaxPropertyValue>),
+    ArrayVariable(String, Vec<AbstractSyntaxPropertyValue>),
     FunctionVariable(String, Function),
     VariablePath(VariablePath),
+    InterpolatedText(InterpolatedText),
+    Expression(Box<Expression>),
+    ExpressionVariable(String, Box<Expression>),
     DataArray(DataArrayId, usize)
 }
 
 #[derive(Debug, Clone)]
 pub enum AbstractSyntaxPropertyValueError {
-    ValueNotExpected(AbstractSyntaxPropertyValue)
+    ValueNotExpected(AbstractSyntaxPropertyValue),
+    OperatorNotSupported(CodeOperator, AbstractSyntaxPropertyValue)
+}
+
+impl std::fmt::Display for AbstractSyntaxPropertyValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ValueNotExpected(value) => write!(f, "value {:?} was not expected", value),
+            Self::OperatorNotSupported(operator, value) => write!(f, "operator {:?} is not supported for {:?}", operator, value)
+        }
+    }
 }
 
 impl AbstractSyntaxPropertyValue {
+    pub fn parse_array(value: &[ArrayTokenResult]) -> Result<Vec<AbstractSyntaxPropertyValue>, AbstractSyntaxTokenError> {
+        let mut values = vec!();
+        for result in value {
+            match result {
+                Ok(SourceTokenPropertyValue::Array(nested)) => values.push(Self::Array(Self::parse_array(nested)?)),
+                Ok(SourceTokenPropertyValue::Code(code)) => values.push(Self::parse_code(code)?),
+                Ok(SourceTokenPropertyValue::Variable(variable)) => values.push(Self::VariablePath(VariablePath::parse(variable.clone())?)),
+                Ok(SourceTokenPropertyValue::USize(value)) => values.push(Self::USize(*value)),
+                Ok(SourceTokenPropertyValue::Int(value)) => values.push(Self::Int(*value)),
+                Ok(token_value) => values.push(token_value.try_into()?),
+                Err(err) => return Err(AbstractSyntaxTokenError::ArrayTokenError(err.clone()))
+            }
+        }
+        Ok(values)
+    }
+
+    pub fn parse_code(value: &[CodeTokenResult]) -> Result<AbstractSyntaxPropertyValue, AbstractSyntaxTokenError> {
+        Ok(Expression::parse(value)?.into_value())
+    }
+
+    pub fn has_dynamic_array_items(value: &[ArrayTokenResult]) -> bool {
+        value.iter().any(|result| match result {
+            Ok(SourceTokenPropertyValue::Array(nested)) => Self::has_dynamic_array_items(nested),
+            Ok(SourceTokenPropertyValue::Code(_)) |
+            Ok(SourceTokenPropertyValue::Variable(_)) => true,
+            _ => false
+        })
+    }
+
     pub fn is_state_variable(&self) -> bool {
         if let Self::VariablePath(value) = self {
             return value.is_state_variable()
@@ -36,23 +82,47 @@ impl AbstractSyntaxPropertyValue {
         Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
 
+    pub fn get_display_value(&self) -> Result<String, AbstractSyntaxPropertyValueError> {
+        match self {
+            AbstractSyntaxPropertyValue::String(value) => Ok(value.clone()),
+            AbstractSyntaxPropertyValue::Bool(value) => Ok(value.to_string()),
+            AbstractSyntaxPropertyValue::Float(value) => Ok(value.to_string()),
+            AbstractSyntaxPropertyValue::USize(value) => Ok(value.to_string()),
+            AbstractSyntaxPropertyValue::Int(value) => Ok(value.to_string()),
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
+        }
+    }
+
     pub fn get_float_value(&self) -> Result<f32, AbstractSyntaxPropertyValueError> {
-        if let AbstractSyntaxPropertyValue::Float(value) = self {
-            return Ok(*value);
+        match self {
+            AbstractSyntaxPropertyValue::Float(value) => Ok(*value),
+            AbstractSyntaxPropertyValue::USize(value) => Ok(*value as f32),
+            AbstractSyntaxPropertyValue::Int(value) => Ok(*value as f32),
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
         }
-        Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
 
-    pub fn get_float_range_value(&self) -> Result<RangeInclusive::<f32>, AbstractSyntaxPropertyValueError> {
-        if let AbstractSyntaxPropertyValue::FloatRange(value) = &self {
-            return Ok(value.into());
+    pub fn get_size_value(&self) -> Result<Size, AbstractSyntaxPropertyValueError> {
+        match self {
+            AbstractSyntaxPropertyValue::Size(value) => Ok(*value),
+            AbstractSyntaxPropertyValue::Float(value) => Ok(Size::points(*value)),
+            AbstractSyntaxPropertyValue::USize(value) => Ok(Size::points(*value as f32)),
+            AbstractSyntaxPropertyValue::Int(value) => Ok(Size::points(*value as f32)),
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
+        }
+    }
+
+    pub fn get_size_range_value(&self) -> Result<SizeRange, AbstractSyntaxPropertyValueError> {
+        match self {
+            AbstractSyntaxPropertyValue::SizeRange(value) => Ok(value.clone()),
+            AbstractSyntaxPropertyValue::Array(values) => SizeRange::from_values(values),
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
         }
-        Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
 
-    pub fn get_usize_range_variable_value(&self) -> Result<(String, USizeRange), AbstractSyntaxPropertyValueError> {
-        if let AbstractSyntaxPropertyValue::USizeRangeVariable(variable, range) = self.clone() {
-            return Ok((variable, range));
+    pub fn get_array_variable_value(&self) -> Result<(String, Vec<AbstractSyntaxPropertyValue>), AbstractSyntaxPropertyValueError> {
+        if let AbstractSyntaxPropertyValue::ArrayVariable(variable, values) = self.clone() {
+            return Ok((variable, values));
         }
         Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
@@ -72,10 +142,13 @@ impl AbstractSyntaxPropertyValue {
     }
     
     pub fn get_colour_value(&self) -> Result<Colour, AbstractSyntaxPropertyValueError> {
-        if let AbstractSyntaxPropertyValue::Colour(value) = &self {
-            return Ok(value.clone());
+        match self {
+            AbstractSyntaxPropertyValue::Colour(value) => Ok(value.clone()),
+            AbstractSyntaxPropertyValue::Array(values) => Colour::from_values(values),
+            AbstractSyntaxPropertyValue::String(value) => Colour::parse_literal(value)
+                .map_err(|_| AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone())),
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
         }
-        Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
     
     pub fn get_function_value(&self) -> Result<Function, AbstractSyntaxPropertyValueError> {
@@ -85,6 +158,13 @@ impl AbstractSyntaxPropertyValue {
         Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
     }
 
+    pub fn get_expression_variable_value(&self) -> Result<(String, Expression), AbstractSyntaxPropertyValueError> {
+        if let AbstractSyntaxPropertyValue::ExpressionVariable(variable, expression) = self.clone() {
+            return Ok((variable, *expression));
+        }
+        Err(AbstractSyntaxPropertyValueError::ValueNotExpected(self.clone()))
+    }
+
     pub fn get_function_variable_value(&self) -> Result<(String, Function), AbstractSyntaxPropertyValueError> {
         if let AbstractSyntaxPropertyValue::FunctionVariable(variable, function) = self.clone() {
             return Ok((variable, function));
@@ -93,13 +173,18 @@ impl AbstractSyntaxPropertyValue {
     }
 }
 
-impl From<&SourceTokenPropertyValue> for AbstractSyntaxPropertyValue {
-    fn from(from: &SourceTokenPropertyValue) -> Self {
+impl TryFrom<&SourceTokenPropertyValue> for AbstractSyntaxPropertyValue {
+    type Error = AbstractSyntaxTokenError;
+
+    fn try_from(from: &SourceTokenPropertyValue) -> Result<Self, Self::Error> {
         match from {
-            SourceTokenPropertyValue::String(value) => Self::String(value.clone()),
-            SourceTokenPropertyValue::Float(value) => Self::Float(*value as f32),
-            SourceTokenPropertyValue::Variable(value) => Self::VariablePath(VariablePath::parse(value.clone()).unwrap()),
-            _  => panic!(),
+            SourceTokenPropertyValue::String(value) => Ok(Self::String(value.clone())),
+            SourceTokenPropertyValue::Bool(value) => Ok(Self::Bool(*value)),
+            SourceTokenPropertyValue::Float(value) => Ok(Self::Float(*value as f32)),
+            SourceTokenPropertyValue::Variable(value) => Ok(Self::VariablePath(VariablePath::parse(value.clone())?)),
+            SourceTokenPropertyValue::Colour(value) => Ok(Self::Colour((*value).into())),
+            SourceTokenPropertyValue::Size(value, unit) => Ok(Self::Size(Size::new(*value as f32, *unit))),
+            value => Err(AbstractSyntaxTokenError::UnexpectedPropertyValue(format!("{:?}", value))),
         }
     }
 }
\ No newline at end of file
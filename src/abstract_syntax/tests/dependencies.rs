@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+const APP_SOURCE: &str = "<root>\
+    <import name=\"header\" path=\"./header.rux\" />\
+    <import name=\"footer\" path=\"./footer.rux\" />\
+    <header /><footer />\
+</root>";
+const HEADER_SOURCE: &str = "<control><import name=\"title\" path=\"./title.rux\" /><title /></control>";
+
+fn location(path: &str) -> SourceLocation {
+    MemoryFileSystem::location(path)
+}
+
+fn locations(paths: &[&str]) -> BTreeSet<SourceLocation> {
+    paths.iter().map(|path| location(path)).collect()
+}
+
+fn example_file_system() -> MemoryFileSystem {
+    MemoryFileSystem::default()
+        .with_source("app.rux", APP_SOURCE)
+        .with_source("header.rux", HEADER_SOURCE)
+        .with_source("title.rux", "<control><label text=\"title\" /></control>")
+        .with_source("footer.rux", "<control><label text=\"footer\" /></control>")
+}
+
+struct LinkedSources {
+    source_files: SourceFiles,
+    stream_lookup: AbstractSyntaxTokenStreamLookup,
+    linked_lookup: AbstractSyntaxTokenStreamLookup,
+    dependencies: AbstractSyntaxDependencyGraph
+}
+
+impl LinkedSources {
+    fn new(file_system: &MemoryFileSystem) -> Self {
+        let mut linked_sources = Self {
+            source_files: file_system.source_files(),
+            stream_lookup: AbstractSyntaxTokenStreamLookup::default(),
+            linked_lookup: AbstractSyntaxTokenStreamLookup::default(),
+            dependencies: AbstractSyntaxDependencyGraph::default()
+        };
+        linked_sources.process();
+        linked_sources
+    }
+
+    fn process(&mut self) -> Vec<SourceLocation> {
+        let changes = self.source_files.process().unwrap();
+        build_streams(&changes, &mut self.source_files, &RegisteredControls::default(), &mut self.stream_lookup);
+        for changed in changes.iter() {
+            match self.stream_lookup.get(changed) {
+                Some(stream) => self.dependencies.update(changed, collect_stream_imports(&location("app.rux"), stream)),
+                None => self.dependencies.remove(changed)
+            }
+        }
+        let dependents = self.dependencies.dependents_of(changes.iter());
+        relink_streams(&location("app.rux"), dependents, &self.stream_lookup, &self.dependencies, &mut self.linked_lookup)
+    }
+}
+
+#[test]
+fn dependency_graph_reports_imports_and_importers() {
+    let linked_sources = LinkedSources::new(&example_file_system());
+    let dependencies = &linked_sources.dependencies;
+
+    assert_eq!(vec!(location("footer.rux"), location("header.rux")), dependencies.imports_of(&location("app.rux")));
+    assert_eq!(vec!(location("header.rux")), dependencies.importers_of(&location("title.rux")));
+    assert_eq!(vec!(location("app.rux")), dependencies.importers_of(&location("header.rux")));
+    assert!(dependencies.importers_of(&location("app.rux")).is_empty());
+}
+
+#[test]
+fn dependents_include_transitive_importers() {
+    let linked_sources = LinkedSources::new(&example_file_system());
+    assert_eq!(
+        locations(&["app.rux", "header.rux", "title.rux"]),
+        linked_sources.dependencies.dependents_of(&locations(&["title.rux"]))
+    );
+    assert_eq!(locations(&["app.rux", "footer.rux"]), linked_sources.dependencies.dependents_of(&locations(&["footer.rux"])));
+}
+
+#[test]
+fn leaf_change_only_relinks_dependent_streams() {
+    let file_system = example_file_system();
+    let mut linked_sources = LinkedSources::new(&file_system);
+
+    file_system.write("title.rux", "<control><label text=\"changed\" /></control>");
+    assert_eq!(vec!(location("title.rux"), location("header.rux"), location("app.rux")), linked_sources.process());
+
+    file_system.write("footer.rux", "<control />");
+    assert_eq!(vec!(location("footer.rux"), location("app.rux")), linked_sources.process());
+}
+
+#[test]
+fn removed_import_updates_importers() {
+    let file_system = example_file_system();
+    let mut linked_sources = LinkedSources::new(&file_system);
+
+    file_system.write("header.rux", "<control />");
+    linked_sources.process();
+
+    assert!(linked_sources.dependencies.importers_of(&location("title.rux")).is_empty());
+    assert!(linked_sources.dependencies.imports_of(&location("header.rux")).is_empty());
+}
+
+#[test]
+fn cyclic_imports_link_without_recursing_forever() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root><import name=\"a\" path=\"./a.rux\" /><a /></root>")
+        .with_source("a.rux", "<control><import name=\"b\" path=\"./b.rux\" /><b /></control>")
+        .with_source("b.rux", "<control><import name=\"a\" path=\"./a.rux\" /><a /></control>");
+    let linked_sources = LinkedSources::new(&file_system);
+
+    assert_eq!(3, linked_sources.linked_lookup.len());
+    assert_eq!(locations(&["a.rux", "app.rux", "b.rux"]), linked_sources.dependencies.dependents_of(&locations(&["a.rux"])));
+}
\ No newline at end of file
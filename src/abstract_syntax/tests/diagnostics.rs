@@ -0,0 +1,182 @@
+use crate::prelude::*;
+
+fn collect_texts(graph: &AbstractSyntaxGraph, node: &AbstractSyntaxGraphNode, texts: &mut Vec<String>) {
+    for property in node.properties() {
+        if property.property_type() == &AbstractSyntaxPropertyType::Text {
+            texts.push(property.value().get_string_value().unwrap());
+        }
+    }
+    for child in graph.get_children(node) {
+        collect_texts(graph, child, texts);
+    }
+}
+
+fn build(ast: &mut AbstractSyntax, source_files: &mut SourceFiles, context: &mut DataContext) -> Vec<String> {
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, source_files, context);
+    let mut texts = vec!();
+    if let Some(root) = ast.graph().get_root() {
+        collect_texts(ast.graph(), root, &mut texts);
+    }
+    texts
+}
+
+fn diagnostics(ast: &AbstractSyntax) -> Vec<String> {
+    ast.diagnostics().map(|diagnostic| diagnostic.to_string()).collect()
+}
+
+fn create_pipeline() -> (MemoryFileSystem, SourceFiles, DataContext, AbstractSyntax) {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root><label text=\"app\" /></root>");
+    let source_files = file_system.source_files();
+    (file_system, source_files, create_data_context(), AbstractSyntax::default())
+}
+
+#[test]
+fn broken_source_keeps_previous_graph() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><label text=\"broken\"></root>");
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(vec!("memory/app.rux:1:8: control is never closed"), diagnostics(&ast));
+}
+
+#[test]
+fn fixed_source_clears_diagnostics() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+    file_system.write("app.rux", "<root><label text=\"broken\"></root>");
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><label text=\"fixed\" /></root>");
+    assert_eq!(vec!("fixed"), build(&mut ast, &mut source_files, &mut context));
+    assert!(diagnostics(&ast).is_empty());
+}
+
+#[test]
+fn broken_import_keeps_previous_graph() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root><import name=\"panel\" path=\"./panel.rux\" /><panel /></root>")
+        .with_source("panel.rux", "<control><label text=\"panel\" /></control>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("panel.rux", "<control><label text=#zzz /></control>");
+    assert_eq!(vec!("panel"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(vec!("memory/panel.rux:1:22: could not parse colour value"), diagnostics(&ast));
+}
+
+#[test]
+fn broken_unimported_source_does_not_block_updates() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("unused.rux", "<control><label text=></control>");
+    file_system.write("app.rux", "<root><label text=\"changed\" /></root>");
+    assert_eq!(vec!("changed"), build(&mut ast, &mut source_files, &mut context));
+    assert!(diagnostics(&ast).is_empty());
+}
+
+#[test]
+fn missing_import_is_reported() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><import name=\"panel\" path=\"./missing.rux\" /><panel /></root>");
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert!(diagnostics(&ast)[0].contains("memory/missing.rux was not found"));
+}
+
+#[test]
+fn missing_root_is_reported() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<control />");
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(vec!("no root found"), diagnostics(&ast));
+
+    file_system.write("app.rux", "<root><label text=\"restored\" /></root>");
+    assert_eq!(vec!("restored"), build(&mut ast, &mut source_files, &mut context));
+    assert!(diagnostics(&ast).is_empty());
+}
+
+#[test]
+fn data_errors_keep_previous_graph() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><label text=$missing /></root>");
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert!(diagnostics(&ast)[0].starts_with("memory/app.rux:1:"));
+}
+
+#[test]
+fn nested_token_errors_use_their_own_span() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><label text={1a} /></root>");
+    build(&mut ast, &mut source_files, &mut context);
+    assert_eq!(vec!("memory/app.rux:1:22: could not parse number value \"1a\""), diagnostics(&ast));
+}
+
+#[test]
+fn runtime_diagnostics_persist_across_frames() {
+    let mut overlay = DiagnosticsOverlay::default();
+    overlay.add_runtime_diagnostic(Diagnostic::error("action failed"));
+    overlay.end_frame();
+    overlay.end_frame();
+
+    assert!(overlay.is_open());
+    assert!(overlay.diagnostics().contains(&Diagnostic::error("action failed")));
+
+    overlay.add_runtime_diagnostic(Diagnostic::error("other action failed"));
+    overlay.end_frame();
+    assert_eq!(2, overlay.diagnostics().len());
+
+    overlay.dismiss();
+    overlay.end_frame();
+    assert!(overlay.diagnostics().is_empty());
+}
+
+#[test]
+fn dismissed_overlay_reopens_only_for_new_diagnostics() {
+    let mut overlay = DiagnosticsOverlay::default();
+    overlay.add_frame_diagnostic(Diagnostic::error("first"));
+    overlay.add_runtime_diagnostic(Diagnostic::error("action failed"));
+    overlay.end_frame();
+    overlay.dismiss();
+
+    overlay.add_frame_diagnostic(Diagnostic::error("first"));
+    overlay.end_frame();
+    assert!(!overlay.is_open());
+    assert_eq!(1, overlay.diagnostics().len());
+
+    overlay.add_frame_diagnostic(Diagnostic::error("first"));
+    overlay.add_frame_diagnostic(Diagnostic::error("second"));
+    overlay.end_frame();
+    assert!(overlay.is_open());
+}
+
+#[test]
+fn unopened_control_is_reported() {
+    let (file_system, mut source_files, mut context, mut ast) = create_pipeline();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("app.rux", "<root><label text=\"app\" /></root></label>");
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(vec!("memory/app.rux:1:41: closing tag has no open control"), diagnostics(&ast));
+}
+
+#[test]
+fn unmatched_end_control_is_an_error() {
+    let controls = RegisteredControls::default();
+    let mut visitor = create_ast_token_visitor(&controls);
+    visitor.end_control("label", &SourceSpan::default());
+
+    assert!(format!("{:?}", visitor.ast()).contains("UnopenedControl(\"label\")"));
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod dependencies;
+#[cfg(test)]
+mod pipeline;
+#[cfg(test)]
+mod diagnostics;
+#[cfg(test)]
+mod suggestions;
\ No newline at end of file
@@ -0,0 +1,168 @@
+use crate::prelude::*;
+
+const APP_SOURCE: &str = "<root><import name=\"panel\" path=\"./controls/panel.rux\" /><label text=\"app\" /><panel /></root>";
+
+fn collect_texts(graph: &AbstractSyntaxGraph, node: &AbstractSyntaxGraphNode, texts: &mut Vec<String>) {
+    for property in node.properties() {
+        if property.property_type() == &AbstractSyntaxPropertyType::Text {
+            texts.push(property.value().get_string_value().unwrap());
+        }
+    }
+    for child in graph.get_children(node) {
+        collect_texts(graph, child, texts);
+    }
+}
+
+fn build(ast: &mut AbstractSyntax, source_files: &mut SourceFiles, context: &mut DataContext) -> Vec<String> {
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, source_files, context);
+    let mut texts = vec!();
+    collect_texts(ast.graph(), ast.graph().get_root().unwrap(), &mut texts);
+    texts
+}
+
+#[test]
+fn memory_sources_build_linked_graph() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", APP_SOURCE)
+        .with_source("controls/panel.rux", "<control><label text=\"panel\" /></control>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+
+    assert_eq!(vec!("app", "panel"), build(&mut ast, &mut source_files, &mut context));
+}
+
+#[test]
+fn memory_source_changes_rebuild_linked_graph() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", APP_SOURCE)
+        .with_source("controls/panel.rux", "<control><label text=\"panel\" /></control>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.write("controls/panel.rux", "<control><label text=\"changed\" /><label text=\"added\" /></control>");
+    assert_eq!(vec!("app", "changed", "added"), build(&mut ast, &mut source_files, &mut context));
+    assert!(source_files.process().unwrap().is_empty());
+}
+
+#[test]
+fn memory_source_monitor_reports_created_modified_and_deleted_sources() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", APP_SOURCE);
+    file_system.write("other.rux", "<control />");
+    file_system.write("app.rux", APP_SOURCE);
+    file_system.remove("other.rux");
+
+    assert!(matches!(file_system.try_get_file_changed(), Ok(FileMonitorFileChange::Create(location)) if location == MemoryFileSystem::location("other.rux")));
+    assert!(matches!(file_system.try_get_file_changed(), Ok(FileMonitorFileChange::Modify(location)) if location == MemoryFileSystem::location("app.rux")));
+    assert!(matches!(file_system.try_get_file_changed(), Ok(FileMonitorFileChange::Delete(location)) if location == MemoryFileSystem::location("other.rux")));
+    assert!(matches!(file_system.try_get_file_changed(), Err(FileMonitorWatchError::NoFileChanges)));
+    assert!(file_system.read_source_at_location(&MemoryFileSystem::location("other.rux")).is_err());
+}
+
+#[test]
+fn renamed_root_source_is_found_again() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", APP_SOURCE)
+        .with_source("controls/panel.rux", "<control><label text=\"panel\" /></control>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    build(&mut ast, &mut source_files, &mut context);
+
+    file_system.rename("app.rux", "main.rux");
+    assert_eq!(vec!("app", "panel"), build(&mut ast, &mut source_files, &mut context));
+}
+
+#[test]
+fn active_root_selects_named_screen() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root name=\"main\"><label text=\"main\" /></root>")
+        .with_source("settings.rux", "<root name=\"settings\"><label text=\"settings\" /></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    assert_eq!(vec!("main"), build(&mut ast, &mut source_files, &mut context));
+
+    context.set_active_root("settings");
+    assert_eq!(vec!("settings"), build(&mut ast, &mut source_files, &mut context));
+
+    context.set_active_root("missing");
+    assert_eq!(vec!("main"), build(&mut ast, &mut source_files, &mut context));
+}
+
+#[test]
+fn unknown_active_root_is_reported_with_suggestion() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root name=\"main\"><label text=\"main\" /></root>")
+        .with_source("settings.rux", "<root name=\"settings\"><label text=\"settings\" /></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    build(&mut ast, &mut source_files, &mut context);
+    assert_eq!(0, ast.diagnostics().count());
+
+    context.set_active_root("setings");
+    assert_eq!(vec!("main"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(
+        vec!("root \"setings\" not found, using memory/app.rux, did you mean \"settings\"?"),
+        ast.diagnostics().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>()
+    );
+
+    context.set_active_root("settings");
+    build(&mut ast, &mut source_files, &mut context);
+    assert_eq!(0, ast.diagnostics().count());
+}
+
+#[test]
+fn duplicate_root_names_are_reported() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root name=\"main\"><label text=\"app\" /></root>")
+        .with_source("other.rux", "<root name=\"main\"><label text=\"other\" /></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    assert_eq!(vec!("app"), build(&mut ast, &mut source_files, &mut context));
+    assert_eq!(
+        vec!("root \"main\" is declared in memory/app.rux, memory/other.rux, using memory/app.rux"),
+        ast.diagnostics().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>()
+    );
+}
+
+#[test]
+fn switch_root_action_changes_active_screen() {
+    let file_system = MemoryFileSystem::default()
+        .with_source("app.rux", "<root><label text=\"main\" /></root>")
+        .with_source("settings.rux", "<root name=\"settings\"><label text=\"settings\" /></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    assert_eq!(vec!("main"), build(&mut ast, &mut source_files, &mut context));
+
+    let switch = Function::new(String::from(SWITCH_ROOT_ACTION), vec!(AbstractSyntaxPropertyValue::String(String::from("settings"))));
+    context.run_action_function(&switch).unwrap();
+    assert_eq!(Some("settings"), context.active_root());
+    assert_eq!(vec!("settings"), build(&mut ast, &mut source_files, &mut context));
+}
+
+#[test]
+fn example_sources_build_every_screen() {
+    let paths = FilePaths::new("examples/assets/first");
+    let mut source_files = SourceFiles::from_backends(
+        paths,
+        Box::new(create_source_file_reader()),
+        Box::new(create_file_system_source_location_walker()),
+        Box::new(StaticFileMonitor)
+    );
+    let mut context = create_data_context();
+    context.controls_mut().register_control(crate::examples::first::create_gauge_control());
+    crate::examples::first::register(&mut context);
+    let mut ast = AbstractSyntax::default();
+    assert!(build(&mut ast, &mut source_files, &mut context).contains(&String::from("settings")));
+    assert_eq!(Vec::<String>::new(), ast.diagnostics().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>());
+
+    context.set_active_root("settings");
+    assert_eq!(vec!("back", "settings"), build(&mut ast, &mut source_files, &mut context));
+}
\ No newline at end of file
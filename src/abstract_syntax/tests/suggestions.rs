@@ -0,0 +1,88 @@
+use crate::prelude::*;
+
+fn build_diagnostics(sources: &[(&str, &str)]) -> Vec<(DiagnosticSeverity, String)> {
+    let file_system = sources.iter()
+        .fold(MemoryFileSystem::default(), |file_system, (path, source)| file_system.with_source(path, source));
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+    ast.diagnostics()
+        .map(|diagnostic| (diagnostic.severity(), diagnostic.message().to_string()))
+        .collect()
+}
+
+#[test]
+fn edit_distance_counts_single_character_edits() {
+    assert_eq!(0, edit_distance("label", "label"));
+    assert_eq!(1, edit_distance("lable", "label"));
+    assert_eq!(1, edit_distance("labl", "label"));
+    assert_eq!(3, edit_distance("", "abc"));
+}
+
+#[test]
+fn closest_match_ignores_distant_names() {
+    assert_eq!(Some("heading"), closest_match("headng", vec!("label", "heading")));
+    assert_eq!(None, closest_match("panel", vec!("label", "heading")));
+    assert_eq!(None, closest_match("label", vec!("label")));
+}
+
+#[test]
+fn unknown_control_suggests_standard_control() {
+    let diagnostics = build_diagnostics(&[("app.rux", "<root><lable text=\"a\" /></root>")]);
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "unknown control \"lable\", did you mean \"label\"?".to_string())), diagnostics);
+}
+
+#[test]
+fn unknown_control_suggests_import() {
+    let diagnostics = build_diagnostics(&[
+        ("app.rux", "<root><import name=\"settings\" path=\"settings.rux\" /><setings /></root>"),
+        ("settings.rux", "<control><label text=\"a\" /></control>")
+    ]);
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "unknown control \"setings\", did you mean \"settings\"?".to_string())), diagnostics);
+}
+
+#[test]
+fn unknown_property_suggests_control_property() {
+    let diagnostics = build_diagnostics(&[("app.rux", "<root><label text=\"a\" colur=\"red\" /></root>")]);
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "unknown property \"colur\", did you mean \"colour\"?".to_string())), diagnostics);
+}
+
+#[test]
+fn unknown_action_suggests_registered_action() {
+    let diagnostics = build_diagnostics(&[(
+        "app.rux",
+        "<root><selectable-label text=\"a\" selected=false on-select={ruxy::swich_root(\"main\")} /></root>"
+    )]);
+
+    assert_eq!(vec!((
+        DiagnosticSeverity::Warning,
+        "unknown action function \"ruxy::swich_root\", did you mean \"ruxy::switch_root\"?".to_string()
+    )), diagnostics);
+}
+
+#[test]
+fn unknown_selector_is_reported() {
+    let diagnostics = build_diagnostics(&[("app.rux", "<root><label text={get_title()} /></root>")]);
+
+    assert!(diagnostics.contains(&(DiagnosticSeverity::Warning, "unknown selector function \"get_title\"".to_string())));
+    assert!(diagnostics.contains(&(DiagnosticSeverity::Error, "function \"get_title\" was not found".to_string())));
+}
+
+#[test]
+fn unknown_flag_suggests_control_property() {
+    let diagnostics = build_diagnostics(&[("app.rux", "<root><label text=\"a\" strongg /></root>")]);
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "unknown property \"strongg\", did you mean \"strong\"?".to_string())), diagnostics);
+}
+
+#[test]
+fn flag_without_value_is_reported() {
+    let diagnostics = build_diagnostics(&[("app.rux", "<root><label text /></root>")]);
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "property \"text\" expects a Text value".to_string())), diagnostics);
+}
\ No newline at end of file
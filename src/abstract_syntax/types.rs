@@ -7,20 +7,18 @@ pub struct Function {
 }
 
 impl Function {
-    pub fn parse(value: &Vec<CodeTokenResult>) -> Result<Function, AbstractSyntaxTokenError> {
-        let mut function = Self::default();
-        for result in value {
-            match result {
-                Ok(token_value) => match token_value {
-                    CodeTokenPropertyValue::StartFunction(function_name) => function.name = function_name.clone(),
-                    CodeTokenPropertyValue::PropertyValue(property_value) => function.arguments.push(property_value.into()),
-                    CodeTokenPropertyValue::EndFunction => {},
-                },
-                Err(err) => return Err(AbstractSyntaxTokenError::CodeTokenError(err.clone())),
-            }
+    pub fn new(name: String, arguments: Vec<AbstractSyntaxPropertyValue>) -> Self {
+        Self {
+            name,
+            arguments
         }
+    }
 
-        Ok(function)
+    pub fn parse(value: &[CodeTokenResult]) -> Result<Function, AbstractSyntaxTokenError> {
+        match Expression::parse(value)? {
+            Expression::Value(AbstractSyntaxPropertyValue::Selector(function)) => Ok(function),
+            _ => Err(AbstractSyntaxTokenError::FunctionExpected)
+        }
     }
 
     pub fn set_arguments(&self, arguments: Vec<AbstractSyntaxPropertyValue>) -> Self {
@@ -39,24 +37,61 @@ impl Function {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct FloatRange {
-    from: f32,
-    to: f32
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Size {
+    value: f32,
+    unit: SizeUnit
 }
 
-impl FloatRange {
-    pub fn parse(value: &Vec<ArrayTokenResult>) -> Result<FloatRange, AbstractSyntaxTokenError> {
-        match collect_array_floats(value, 2) {
-            Ok(values) => Ok(FloatRange { from: values[0], to: values[1] }),
-            Err(_) => Err(AbstractSyntaxTokenError::RangeValueParseError)
+impl Size {
+    pub fn new(value: f32, unit: SizeUnit) -> Self {
+        Self {
+            value,
+            unit
+        }
+    }
+
+    pub fn points(value: f32) -> Self {
+        Self::new(value, SizeUnit::Points)
+    }
+
+    pub fn resolve(&self, available: f32, em: f32) -> f32 {
+        match self.unit {
+            SizeUnit::Points => self.value,
+            SizeUnit::Percent => available * self.value / 100.0,
+            SizeUnit::Em => em * self.value
         }
     }
 }
 
-impl From<&FloatRange> for RangeInclusive<f32> {
-    fn from(from: &FloatRange) -> Self {
-        Self::new(from.from, from.to)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeRange {
+    from: Size,
+    to: Size
+}
+
+impl SizeRange {
+    pub fn new(from: Size, to: Size) -> Self {
+        Self {
+            from,
+            to
+        }
+    }
+
+    pub fn parse(value: &Vec<ArrayTokenResult>) -> Result<SizeRange, AbstractSyntaxTokenError> {
+        match collect_array_sizes(value).and_then(|values| expect_collection_length(values, 2)) {
+            Ok(values) => Ok(SizeRange::new(Size::new(values[0].0, values[0].1), Size::new(values[1].0, values[1].1))),
+            Err(_) => Err(AbstractSyntaxTokenError::RangeValueParseError)
+        }
+    }
+
+    pub fn from_values(values: &[AbstractSyntaxPropertyValue]) -> Result<SizeRange, AbstractSyntaxPropertyValueError> {
+        let values = collect_values(values, 2, |value| value.get_size_value().ok())?;
+        Ok(SizeRange::new(values[0], values[1]))
+    }
+
+    pub fn resolve(&self, available: f32, em: f32) -> RangeInclusive<f32> {
+        RangeInclusive::new(self.from.resolve(available, em), self.to.resolve(available, em))
     }
 }
 
@@ -75,12 +110,20 @@ impl USizeRange {
     }
 
     pub fn parse(value: &Vec<ArrayTokenResult>) -> Result<USizeRange, AbstractSyntaxTokenError> {
-        match collect_array_usizes(value, 2) {
+        match collect_array_usizes(value).and_then(|values| expect_collection_length(values, 2)) {
             Ok(values) => Ok(USizeRange { from: values[0], to: values[1] }),
             Err(_) => Err(AbstractSyntaxTokenError::RangeValueParseError)
         }
     }
 
+    pub fn from_values(values: &[AbstractSyntaxPropertyValue]) -> Result<USizeRange, AbstractSyntaxPropertyValueError> {
+        let values = collect_values(values, 2, |value| match value {
+            AbstractSyntaxPropertyValue::USize(value) => Some(*value),
+            _ => None
+        })?;
+        Ok(USizeRange { from: values[0], to: values[1] })
+    }
+
     pub fn lower_bound(&self) -> usize {
         self.from
     }
@@ -97,7 +140,7 @@ impl From<&USizeRange> for RangeInclusive<usize> {
 }
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Colour {
     pub r: u8,
     pub g: u8,
@@ -107,7 +150,7 @@ pub struct Colour {
 
 impl Colour {
     pub fn parse(value: &Vec<ArrayTokenResult>) -> Result<Colour, AbstractSyntaxTokenError> {
-        match collect_array_unsigned_shorts(value, 4) {
+        match collect_array_unsigned_shorts(value).and_then(|values| expect_collection_length(values, 4)) {
             Ok(values) => Ok(
                 Colour { 
                     r: values[0] as u8,
@@ -119,6 +162,45 @@ impl Colour {
             Err(_err) => Err(AbstractSyntaxTokenError::ColourValueParseError)
         }
     }
+
+    pub fn parse_literal(value: &str) -> Result<Colour, AbstractSyntaxTokenError> {
+        match parse_colour_value(value) {
+            Some(value) => Ok(value.into()),
+            None => Err(AbstractSyntaxTokenError::ColourValueParseError)
+        }
+    }
+
+    pub fn from_values(values: &[AbstractSyntaxPropertyValue]) -> Result<Colour, AbstractSyntaxPropertyValueError> {
+        let values = collect_values(values, 4, |value| match value {
+            AbstractSyntaxPropertyValue::USize(value) => Some(*value as u8),
+            _ => None
+        })?;
+        Ok(Colour { r: values[0], g: values[1], b: values[2], a: values[3] })
+    }
+}
+
+impl From<[u8; 4]> for Colour {
+    fn from(from: [u8; 4]) -> Self {
+        Colour { r: from[0], g: from[1], b: from[2], a: from[3] }
+    }
+}
+
+fn collect_values<T>(
+    values: &[AbstractSyntaxPropertyValue],
+    amount: usize,
+    convert: fn(&AbstractSyntaxPropertyValue) -> Option<T>
+) -> Result<Vec<T>, AbstractSyntaxPropertyValueError> {
+    if values.len() != amount {
+        return Err(AbstractSyntaxPropertyValueError::ValueNotExpected(AbstractSyntaxPropertyValue::Array(values.to_vec())));
+    }
+    let mut collected = vec!();
+    for value in values {
+        match convert(value) {
+            Some(converted) => collected.push(converted),
+            None => return Err(AbstractSyntaxPropertyValueError::ValueNotExpected(value.clone()))
+        }
+    }
+    Ok(collected)
 }
 
 #[derive(Debug, Clone)]
@@ -147,4 +229,96 @@ impl VariablePath {
     pub fn property_part(&self) -> &Option<String> {
         &self.1
     }
-}
\ No newline at end of file
+}
+impl std::fmt::Display for VariablePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.1 {
+            Some(property_part) => write!(f, "{}.{}", self.0, property_part),
+            None => write!(f, "{}", self.0)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum InterpolatedTextPart {
+    Text(String),
+    Variable(VariablePath)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InterpolatedText(Vec<InterpolatedTextPart>);
+
+impl InterpolatedText {
+    pub fn parse(value: &str) -> Result<InterpolatedText, AbstractSyntaxTokenError> {
+        let characters: Vec<char> = value.chars().collect();
+        let mut interpolated_text = Self::default();
+        let mut text = String::new();
+        let mut position = 0;
+
+        while position < characters.len() {
+            let character = characters[position];
+            position += 1;
+            if character != '$' {
+                text.push(character);
+                continue;
+            }
+            if characters.get(position) == Some(&'$') {
+                text.push('$');
+                position += 1;
+                continue;
+            }
+
+            let start = position;
+            while position < characters.len() && is_variable_character(&characters, position) {
+                position += 1;
+            }
+            if start == position {
+                text.push('$');
+                continue;
+            }
+
+            if !text.is_empty() {
+                interpolated_text.0.push(InterpolatedTextPart::Text(text));
+                text = String::new();
+            }
+            let variable: String = characters[start..position].iter().collect();
+            interpolated_text.0.push(InterpolatedTextPart::Variable(VariablePath::parse(variable)?));
+        }
+
+        if !text.is_empty() {
+            interpolated_text.0.push(InterpolatedTextPart::Text(text));
+        }
+        Ok(interpolated_text)
+    }
+
+    pub fn parts(&self) -> &Vec<InterpolatedTextPart> {
+        &self.0
+    }
+
+    pub fn has_variables(&self) -> bool {
+        self.0.iter().any(|part| matches!(part, InterpolatedTextPart::Variable(_)))
+    }
+}
+
+impl std::fmt::Display for InterpolatedText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for part in &self.0 {
+            match part {
+                InterpolatedTextPart::Text(text) => write!(f, "{}", text)?,
+                InterpolatedTextPart::Variable(variable) => write!(f, "${}", variable)?
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_variable_character(characters: &[char], position: usize) -> bool {
+    let character = characters[position];
+    if character == '.' {
+        return match characters.get(position + 1) {
+            Some(next) => next.is_alphanumeric() || *next == '_',
+            None => false
+        };
+    }
+    character.is_alphanumeric() || character == '_'
+}
@@ -14,7 +14,6 @@ impl From<SourceReaderError> for RuxError {
     }
 }
 
-
 impl From<FileMonitorError> for RuxError {
     fn from(from: FileMonitorError) -> Self {
         RuxError::FileMonitoringError(from)
@@ -34,7 +33,10 @@ impl From<RendererError> for RuxError {
 }
 
 pub struct Application {
-    relative_rux_folder_path: &'static str,
+    file_paths: FilePaths,
+    embedded_sources: Option<EmbeddedSources>,
+    root_name: Option<String>,
+    controls: Vec<CustomControl>,
     file_monitor_poll: Duration,
     on_context: Box<dyn FnOnce(&mut DataContext) -> ()>
 }
@@ -42,7 +44,10 @@ pub struct Application {
 impl Default for Application {
     fn default() -> Self {
         Self {
-            relative_rux_folder_path: "",
+            file_paths: FilePaths::default(),
+            embedded_sources: None,
+            root_name: None,
+            controls: vec!(),
             file_monitor_poll: Duration::default(),
             on_context: Box::new(|_| {})
         }
@@ -55,8 +60,33 @@ impl Application {
         self
     }
 
-    pub fn with_file_path(mut self, path: &'static str) -> Self {
-        self.relative_rux_folder_path = path;
+    pub fn with_file_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file_paths = self.file_paths.with_folder_path(path);
+        self
+    }
+
+    pub fn with_file_path_base(mut self, base: FilePathBase) -> Self {
+        self.file_paths = self.file_paths.with_base(base);
+        self
+    }
+
+    pub fn with_file_path_environment_override(mut self, variable: impl Into<String>) -> Self {
+        self.file_paths = self.file_paths.with_environment_override(variable);
+        self
+    }
+
+    pub fn with_embedded_sources(mut self, sources: Option<EmbeddedSources>) -> Self {
+        self.embedded_sources = sources;
+        self
+    }
+
+    pub fn with_root(mut self, name: impl Into<String>) -> Self {
+        self.root_name = Some(name.into());
+        self
+    }
+
+    pub fn with_control(mut self, control: CustomControl) -> Self {
+        self.controls.push(control);
         self
     }
 
@@ -70,14 +100,32 @@ impl Application {
         self
     }
 
+    pub fn format_sources(self) -> Result<(), Vec<SourceFileFormatError>> {
+        let locations = format_source_files(&self.file_paths, &SourceFormatter::default())?;
+        for location in locations {
+            info!("formatted {:?}", location);
+        }
+        Ok(())
+    }
+
     pub fn build(self) -> Result<ApplicationRunner, RuxError> {
         let event_loop = create_system_event_loop();
         let mut data_context = create_data_context();
+        if let Some(root_name) = self.root_name {
+            data_context.set_active_root(root_name);
+        }
+        for control in self.controls {
+            data_context.controls_mut().register_control(control);
+        }
         (self.on_context)(&mut data_context);
         
+        let source_files = match self.embedded_sources {
+            Some(sources) => SourceFiles::embedded(self.file_paths, sources),
+            None => SourceFiles::new(self.file_paths, self.file_monitor_poll)?
+        };
         Ok(ApplicationRunner::new(
             data_context,
-            SourceFiles::new(self.relative_rux_folder_path, self.file_monitor_poll)?,
+            source_files,
             AbstractSyntax::default(),
             Renderer::new(&event_loop)?,
             event_loop
@@ -4,7 +4,8 @@ pub struct AbstractSyntaxGraphBuilder {
     ast: AbstractSyntaxGraph,
     strategies: Vec<Box<dyn BuildAbstractSyntaxGraphStreamStrategy>>,
     positions: Vec<usize>,
-    current_node: AbstractSyntaxGraphNodeId
+    current_node: AbstractSyntaxGraphNodeId,
+    diagnostics: Diagnostics
 }
 
 impl Default for AbstractSyntaxGraphBuilder {
@@ -13,27 +14,41 @@ impl Default for AbstractSyntaxGraphBuilder {
             ast: Default::default(),
             strategies: vec!(), 
             positions: vec!(), 
-            current_node: Default::default()
+            current_node: Default::default(),
+            diagnostics: Default::default()
         }
     }
 }
 
 impl AbstractSyntaxGraphBuilder {
-    pub fn ast(self) -> AbstractSyntaxGraph {
-        self.ast
+    pub fn ast(self) -> Result<AbstractSyntaxGraph, Diagnostics> {
+        match self.diagnostics.is_empty() {
+            true => Ok(self.ast),
+            false => Err(self.diagnostics)
+        }
+    }
+
+    fn data_error(&mut self, error: DataContextError, span: &SourceSpan) {
+        self.diagnostics.push(Diagnostic::error(error.to_string()).with_span(span));
     }
 }
 
 impl AbstractSyntaxTokenStreamVisitor for AbstractSyntaxGraphBuilder {
-    fn token_error(&mut self, error: &AbstractSyntaxTokenError) {
-        panic!("{:?}", error)
+    fn token_error(&mut self, error: &AbstractSyntaxTokenError, span: &SourceSpan) {
+        self.diagnostics.push(Diagnostic::token_error(error, span));
     }
 
-    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, context: &mut DataContext) {
+    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, span: &SourceSpan, context: &mut DataContext) {
         let mut action = StartNodeAction::Continue;
 
         if let Some(parent_strategy) = self.strategies.last_mut() {
-            action = parent_strategy.start_child_node(&mut self.ast, context);
+            match parent_strategy.start_child_node(&mut self.ast, context) {
+                Ok(parent_action) => action = parent_action,
+                Err(error) => {
+                    self.data_error(error, span);
+                    action = StartNodeAction::Prevent;
+                }
+            }
         }
         
         
@@ -47,12 +62,19 @@ impl AbstractSyntaxTokenStreamVisitor for AbstractSyntaxGraphBuilder {
         self.strategies.push(strategy);
     }
 
-    fn property(&mut self, property: &AbstractSyntaxProperty, context: &mut DataContext) {
-        self.strategies.last_mut().unwrap().property(self.current_node, property.clone(), &mut self.ast, context);
+    fn property(&mut self, property: &AbstractSyntaxProperty, span: &SourceSpan, context: &mut DataContext) {
+        if let Some(strategy) = self.strategies.last_mut() {
+            if let Err(error) = strategy.property(self.current_node, property.clone(), &mut self.ast, context) {
+                self.data_error(error, span);
+            }
+        }
     }
 
-    fn end_node(&mut self, _node_type: &AbstractSyntaxControlType, context: &mut DataContext) -> EndNodeAction {
-        let mut strategy = self.strategies.pop().unwrap();
+    fn end_node(&mut self, _node_type: &AbstractSyntaxControlType, _span: &SourceSpan, context: &mut DataContext) -> EndNodeAction {
+        let mut strategy = match self.strategies.pop() {
+            Some(strategy) => strategy,
+            None => return EndNodeAction::Continue
+        };
         let ending_node = self.current_node;
         self.current_node = strategy.end_node(ending_node, &mut self.ast);
         
@@ -10,7 +10,7 @@ use crate::prelude::*;
 pub fn build_graph(
     context: &mut DataContext,
     linked_stream: &mut AbstractSyntaxTokenStream
-) ->  AbstractSyntaxGraph {
+) -> Result<AbstractSyntaxGraph, Diagnostics> {
     let mut graph_builder = AbstractSyntaxGraphBuilder::default();
     linked_stream.accept(&mut graph_builder, context);
     graph_builder.ast()
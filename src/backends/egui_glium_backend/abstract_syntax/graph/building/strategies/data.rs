@@ -3,7 +3,7 @@ use crate::prelude::*;
 
 #[derive(Default)]
 pub struct LetBuildAbstractSyntaxGraphStreamStrategy {
-    function_variable: Option<(String, Function)>,
+    expression_variable: Option<(String, Expression)>,
 }
 
 impl BuildAbstractSyntaxGraphStreamStrategy for LetBuildAbstractSyntaxGraphStreamStrategy {
@@ -15,17 +15,17 @@ impl BuildAbstractSyntaxGraphStreamStrategy for LetBuildAbstractSyntaxGraphStrea
         ast.get_parent(node)
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) {
-        self.function_variable = Some(property.value().get_function_variable_value().unwrap());
+    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<(), DataContextError> {
+        self.expression_variable = Some(property.value().get_expression_variable_value()?);
+        Ok(())
     }
 
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> StartNodeAction {
-        if let Some((variable, function)) = &self.function_variable {
-            let function = context.replace_variable_data_in_function(function).unwrap();
-            let function_value = context.run_selector_function(&function).unwrap();
-            context.set_variable(variable.clone(), function_value);
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
+        if let Some((variable, expression)) = &self.expression_variable {
+            let expression_value = context.evaluate_expression(expression)?;
+            context.set_variable(variable.clone(), expression_value);
         }
-        StartNodeAction::Continue
+        Ok(StartNodeAction::Continue)
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
@@ -16,26 +16,26 @@ impl BuildAbstractSyntaxGraphStreamStrategy for ForEachBuildAbstractSyntaxGraphS
         ast.get_parent(node)
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) {
-        let (variable, function) = property.value().get_function_variable_value().unwrap();
-        let function = context.replace_variable_data_in_function(&function).unwrap();
-        if let AbstractSyntaxPropertyValue::DataArray(array_id, position) = context.run_selector_function(&function).unwrap() {
+    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<(), DataContextError> {
+        let (variable, function) = property.value().get_function_variable_value()?;
+        if let AbstractSyntaxPropertyValue::DataArray(array_id, position) = context.run_selector_function(&function)? {
             self.variable_items = Some((variable, array_id));
             self.position = position
         }
+        Ok(())
     }
 
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> StartNodeAction {
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
         if let Some((variable, array_id)) = &self.variable_items {
             if let Some(array) = context.data_arrays().get(*array_id) {
                 if array.len() == 0 {
-                    return StartNodeAction::Prevent;
+                    return Ok(StartNodeAction::Prevent);
                 }
                 
                 context.set_variable(variable.clone(), AbstractSyntaxPropertyValue::DataArray(*array_id, self.position));
             }
         }
-        StartNodeAction::Continue
+        Ok(StartNodeAction::Continue)
 
     }
 
@@ -71,18 +71,21 @@ impl BuildAbstractSyntaxGraphStreamStrategy for ForBuildAbstractSyntaxGraphStrea
         ast.get_parent(node)
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) {
-        let (variable, range) = property.value().get_usize_range_variable_value().unwrap();
+    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<(), DataContextError> {
+        let (variable, values) = property.value().get_array_variable_value()?;
+        let values = context.replace_variable_data_in_array(&values)?;
+        let range = USizeRange::from_values(&values)?;
         self.variable = Some(variable);
         self.current_position = range.lower_bound();
         self.range = Some(range);
+        Ok(())
     }
 
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> StartNodeAction {
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
         if let Some(variable) = &self.variable {
             context.set_variable(variable.clone(), AbstractSyntaxPropertyValue::USize(self.current_position));
         }
-        StartNodeAction::Continue
+        Ok(StartNodeAction::Continue)
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
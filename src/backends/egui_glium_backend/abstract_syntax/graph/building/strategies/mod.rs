@@ -14,32 +14,32 @@ use crate::prelude::*;
 pub trait BuildAbstractSyntaxGraphStreamStrategy {
     fn start_node(&mut self, parent: AbstractSyntaxGraphNodeId, ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId;
     fn end_node(&mut self, node: AbstractSyntaxGraphNodeId, ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId;
-    fn property(&mut self, node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxGraph, context: &mut DataContext);
-    fn start_child_node(&mut self, ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> StartNodeAction;
+    fn property(&mut self, node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<(), DataContextError>;
+    fn start_child_node(&mut self, ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<StartNodeAction, DataContextError>;
     fn end_child_node(&mut self, context: &mut DataContext) -> EndNodeAction;
 }
 
 pub struct EmptyBuildAbstractSyntaxGraphStreamStrategy;
 
 impl BuildAbstractSyntaxGraphStreamStrategy for EmptyBuildAbstractSyntaxGraphStreamStrategy {
-    fn start_node(&mut self, _parent: AbstractSyntaxGraphNodeId, _ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId {
-        panic!()
+    fn start_node(&mut self, parent: AbstractSyntaxGraphNodeId, _ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId {
+        parent
     }
 
-    fn end_node(&mut self, _node: AbstractSyntaxGraphNodeId, _ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId {
-        panic!()
+    fn end_node(&mut self, node: AbstractSyntaxGraphNodeId, _ast: &mut AbstractSyntaxGraph) -> AbstractSyntaxGraphNodeId {
+        node
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, _property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) {
-        panic!()
+    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, _property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<(), DataContextError> {
+        Ok(())
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
-        panic!()
+        EndNodeAction::Continue
     }
 
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> StartNodeAction {
-        panic!()
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
+        Ok(StartNodeAction::Continue)
     }
 }
 
@@ -54,14 +54,15 @@ impl BuildAbstractSyntaxGraphStreamStrategy for PreventBuildAbstractSyntaxGraphS
         node
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, _property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) {
+    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, _property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<(), DataContextError> {
+        Ok(())
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
         EndNodeAction::Continue
     }
 
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> StartNodeAction {
-        StartNodeAction::Prevent
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
+        Ok(StartNodeAction::Prevent)
     }
 }
\ No newline at end of file
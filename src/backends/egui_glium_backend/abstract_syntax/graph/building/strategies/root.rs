@@ -12,15 +12,16 @@ impl BuildAbstractSyntaxGraphStreamStrategy for RootBuildAbstractSyntaxGraphStre
         AbstractSyntaxGraphNodeId::default()
     }
 
-    fn property(&mut self, _node: AbstractSyntaxGraphNodeId, _property: AbstractSyntaxProperty, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) {
-        panic!()
+    fn property(&mut self, node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<(), DataContextError> {
+        ast.add_node_property(node, property);
+        Ok(())
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
         EndNodeAction::Continue
     }
     
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> StartNodeAction {
-        StartNodeAction::Continue
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
+        Ok(StartNodeAction::Continue)
     }
 }
@@ -13,16 +13,17 @@ impl BuildAbstractSyntaxGraphStreamStrategy for StandardBuildAbstractSyntaxGraph
         ast.get_parent(node)
     }
 
-    fn property(&mut self, node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxGraph, context: &mut DataContext) {
-        let resolved_property = context.replace_variable_data_in_property(property).unwrap();
+    fn property(&mut self, node: AbstractSyntaxGraphNodeId, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxGraph, context: &mut DataContext) -> Result<(), DataContextError> {
+        let resolved_property = context.replace_variable_data_in_property(property)?;
         ast.add_node_property(node, resolved_property);
+        Ok(())
     }
 
     fn end_child_node(&mut self, _context: &mut DataContext) -> EndNodeAction {
         EndNodeAction::Continue
     }
     
-    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> StartNodeAction {
-        StartNodeAction::Continue
+    fn start_child_node(&mut self, _ast: &mut AbstractSyntaxGraph, _context: &mut DataContext) -> Result<StartNodeAction, DataContextError> {
+        Ok(StartNodeAction::Continue)
     }
 }
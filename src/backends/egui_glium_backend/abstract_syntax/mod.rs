@@ -2,8 +2,11 @@ mod tokens;
 mod types;
 mod stream;
 mod graph;
+mod schema;
+mod tests;
 
 pub use tokens::*;
 pub use types::*;
 pub use stream::*;
-pub use graph::*;
\ No newline at end of file
+pub use graph::*;
+pub use schema::*;
\ No newline at end of file
@@ -0,0 +1,234 @@
+use crate::prelude::*;
+
+const STRUCTURAL_CONTROLS: &[&str] = &["control", "import", "for", "for-each", "let"];
+
+const STANDARD_CONTROLS: &[(&str, AbstractSyntaxControlType)] = &[
+    ("root", AbstractSyntaxControlType::Root),
+    ("central-panel", AbstractSyntaxControlType::CentralPanel),
+    ("top-panel", AbstractSyntaxControlType::TopPanel),
+    ("bottom-panel", AbstractSyntaxControlType::BottomPanel),
+    ("left-side-bar", AbstractSyntaxControlType::LeftSidebar),
+    ("right-side-bar", AbstractSyntaxControlType::RightSidebar),
+    ("scroll-area", AbstractSyntaxControlType::ScrollArea),
+    ("separator", AbstractSyntaxControlType::Separator),
+    ("horizontal", AbstractSyntaxControlType::Horizontal),
+    ("vertical", AbstractSyntaxControlType::Vertical),
+    ("label", AbstractSyntaxControlType::Label),
+    ("coloured-label", AbstractSyntaxControlType::ColouredLabel),
+    ("selectable-label", AbstractSyntaxControlType::SelectableLabel),
+    ("heading", AbstractSyntaxControlType::Heading),
+    ("monospace", AbstractSyntaxControlType::Monospace),
+    ("code", AbstractSyntaxControlType::Code)
+];
+
+pub fn standard_control_type(control_name: &str) -> Option<AbstractSyntaxControlType> {
+    STANDARD_CONTROLS.iter()
+        .find(|(name, _)| *name == control_name)
+        .map(|(_, control_type)| *control_type)
+}
+
+pub fn standard_control_name(control_type: AbstractSyntaxControlType) -> Option<&'static str> {
+    STANDARD_CONTROLS.iter()
+        .find(|(_, standard_type)| *standard_type == control_type)
+        .map(|(name, _)| *name)
+}
+
+pub fn control_names() -> impl Iterator<Item = &'static str> + Clone {
+    STRUCTURAL_CONTROLS.iter()
+        .copied()
+        .chain(STANDARD_CONTROLS.iter().map(|(name, _)| *name))
+}
+
+pub fn control_schema(control_type: AbstractSyntaxControlType) -> ControlSchema {
+    use AbstractSyntaxPropertyType as Property;
+    use PropertyValueType as Value;
+    let schema = ControlSchema::new(control_type);
+    match control_type {
+        AbstractSyntaxControlType::Root => schema
+            .with_property("name", Property::Name, Value::String),
+        AbstractSyntaxControlType::TopPanel | AbstractSyntaxControlType::BottomPanel => schema
+            .with_property("id", Property::Id, Value::String)
+            .with_default("resizable", Property::Resizable, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_property("default-height", Property::DefaultHeight, Value::Size)
+            .with_default("height-range", Property::HeightRange, Value::SizeRange, default_panel_range()),
+        AbstractSyntaxControlType::LeftSidebar | AbstractSyntaxControlType::RightSidebar => schema
+            .with_property("id", Property::Id, Value::String)
+            .with_default("resizable", Property::Resizable, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("default-width", Property::DefaultWidth, Value::Size, AbstractSyntaxPropertyValue::Size(Size::points(200.0)))
+            .with_default("width-range", Property::WidthRange, Value::SizeRange, default_panel_range()),
+        AbstractSyntaxControlType::ScrollArea => schema
+            .with_property("id", Property::Id, Value::String)
+            .with_property("max-height", Property::VerticallySized, Value::Size)
+            .with_property("auto-sized", Property::AutoSized, Value::Bool)
+            .with_default("always_show_scroll", Property::AlwaysShowScroll, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_property("scroll_offset", Property::ScrollOffset, Value::Float)
+            .with_default("enable_scrolling", Property::EnableScrolling, Value::Bool, AbstractSyntaxPropertyValue::Bool(true)),
+        AbstractSyntaxControlType::Label => schema
+            .with_text()
+            .with_property("wrap", Property::Wrap, Value::Bool)
+            .with_property("text-style", Property::TextStyle, Value::TextStyle)
+            .with_property("background-colour", Property::BackgroundColour, Value::Colour)
+            .with_property("colour", Property::Colour, Value::Colour)
+            .with_default("code", Property::Code, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("strong", Property::Strong, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("weak", Property::Weak, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("strike-through", Property::Strikethrough, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("underline", Property::Underline, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("italics", Property::Italics, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_default("raised", Property::Raised, Value::Bool, AbstractSyntaxPropertyValue::Bool(false)),
+        AbstractSyntaxControlType::ColouredLabel => schema
+            .with_text()
+            .with_property("colour", Property::Colour, Value::Colour),
+        AbstractSyntaxControlType::SelectableLabel => schema
+            .with_text()
+            .with_default("selected", Property::Selected, Value::Bool, AbstractSyntaxPropertyValue::Bool(false))
+            .with_property("on-select", Property::OnSelect, Value::Function),
+        AbstractSyntaxControlType::Heading | AbstractSyntaxControlType::Monospace | AbstractSyntaxControlType::Code => schema
+            .with_text(),
+        _ => schema
+    }
+}
+
+fn default_panel_range() -> AbstractSyntaxPropertyValue {
+    AbstractSyntaxPropertyValue::SizeRange(SizeRange::new(Size::points(96.0), Size::points(f32::INFINITY)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyValueType {
+    Bool,
+    String,
+    Text,
+    Float,
+    Size,
+    SizeRange,
+    Colour,
+    TextStyle,
+    Function
+}
+
+impl PropertyValueType {
+    pub fn accepts(&self, value: &AbstractSyntaxPropertyValue) -> bool {
+        match value {
+            AbstractSyntaxPropertyValue::VariablePath(_) |
+            AbstractSyntaxPropertyValue::Selector(_) |
+            AbstractSyntaxPropertyValue::Expression(_) => return true,
+            AbstractSyntaxPropertyValue::Array(_) => return matches!(self, Self::SizeRange | Self::Colour),
+            _ => {}
+        }
+        match self {
+            Self::Bool => matches!(value, AbstractSyntaxPropertyValue::Bool(_)),
+            Self::String | Self::TextStyle => matches!(value, AbstractSyntaxPropertyValue::String(_)),
+            Self::Text => matches!(value, AbstractSyntaxPropertyValue::String(_) | AbstractSyntaxPropertyValue::InterpolatedText(_)),
+            Self::Float => matches!(value, AbstractSyntaxPropertyValue::Float(_)),
+            Self::Size => matches!(value, AbstractSyntaxPropertyValue::Size(_)),
+            Self::SizeRange => matches!(value, AbstractSyntaxPropertyValue::SizeRange(_)),
+            Self::Colour => matches!(value, AbstractSyntaxPropertyValue::Colour(_)),
+            Self::Function => matches!(value, AbstractSyntaxPropertyValue::Function(_))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PropertySchema {
+    name: &'static str,
+    property_type: AbstractSyntaxPropertyType,
+    value_type: PropertyValueType,
+    default: Option<AbstractSyntaxPropertyValue>
+}
+
+impl PropertySchema {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn property_type(&self) -> &AbstractSyntaxPropertyType {
+        &self.property_type
+    }
+
+    pub fn value_type(&self) -> PropertyValueType {
+        self.value_type
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ControlSchema {
+    control_name: String,
+    properties: Vec<PropertySchema>
+}
+
+impl ControlSchema {
+    pub fn new(control_type: AbstractSyntaxControlType) -> Self {
+        Self {
+            control_name: standard_control_name(control_type).unwrap_or_default().to_string(),
+            properties: vec!()
+        }
+    }
+
+    pub fn with_control_name(mut self, control_name: impl Into<String>) -> Self {
+        self.control_name = control_name.into();
+        self
+    }
+
+    pub fn with_custom_property(self, name: &'static str, value_type: PropertyValueType) -> Self {
+        self.with_property(name, AbstractSyntaxPropertyType::Custom(name.to_string()), value_type)
+    }
+
+    pub fn with_custom_default(self, name: &'static str, value_type: PropertyValueType, default: AbstractSyntaxPropertyValue) -> Self {
+        self.with_default(name, AbstractSyntaxPropertyType::Custom(name.to_string()), value_type, default)
+    }
+
+    pub fn with_property(self, name: &'static str, property_type: AbstractSyntaxPropertyType, value_type: PropertyValueType) -> Self {
+        self.with_property_schema(PropertySchema { name, property_type, value_type, default: None })
+    }
+
+    pub fn with_default(
+        self,
+        name: &'static str,
+        property_type: AbstractSyntaxPropertyType,
+        value_type: PropertyValueType,
+        default: AbstractSyntaxPropertyValue
+    ) -> Self {
+        self.with_property_schema(PropertySchema { name, property_type, value_type, default: Some(default) })
+    }
+
+    fn with_text(self) -> Self {
+        self.with_default("text", AbstractSyntaxPropertyType::Text, PropertyValueType::Text, AbstractSyntaxPropertyValue::String("".to_string()))
+    }
+
+    fn with_property_schema(mut self, property: PropertySchema) -> Self {
+        self.properties.push(property);
+        self
+    }
+
+    pub fn property(&self, property_type: &AbstractSyntaxPropertyType) -> Option<&PropertySchema> {
+        self.properties.iter().find(|property| &property.property_type == property_type)
+    }
+
+    pub fn find(&self, property_name: &str) -> Option<&PropertySchema> {
+        self.properties.iter().find(|property| property.name == property_name)
+    }
+
+    pub fn property_names(&self) -> impl Iterator<Item = &'static str> + Clone + '_ {
+        self.properties.iter().map(|property| property.name)
+    }
+
+    pub fn validate(&self, property_name: &str, property: &AbstractSyntaxProperty) -> Result<(), String> {
+        match self.property(property.property_type()) {
+            Some(schema) if schema.value_type.accepts(property.value()) => Ok(()),
+            Some(schema) => Err(format!("property {:?} expects a {:?} value", property_name, schema.value_type)),
+            None => Err(format!(
+                "property {:?} is not supported by {:?}, expected one of [{}]",
+                property_name,
+                self.control_name,
+                self.property_names().collect::<Vec<&str>>().join(", ")
+            ))
+        }
+    }
+
+    pub fn with_defaults(&self, properties: &[AbstractSyntaxProperty]) -> Vec<AbstractSyntaxProperty> {
+        self.properties.iter()
+            .filter_map(|schema| schema.default.clone().map(|default| create_ast_property(schema.property_type.clone(), default)))
+            .chain(properties.iter().cloned())
+            .collect()
+    }
+}
\ No newline at end of file
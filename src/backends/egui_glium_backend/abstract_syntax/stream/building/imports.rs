@@ -41,4 +41,8 @@ impl SourceImports {
     pub fn get_path(&self, control_name: &str) -> Option<&String> {
         self.lookup.get(control_name)
     }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.lookup.keys().map(String::as_str)
+    }
 }
\ No newline at end of file
@@ -11,23 +11,26 @@ use crate::prelude::*;
 pub fn build_streams(
     changes: &SourceChanges,
     source_files: &mut SourceFiles,
+    controls: &RegisteredControls,
     stream_lookup: &mut AbstractSyntaxTokenStreamLookup
-) -> Option<SourceLocation> {
-    let mut root_location = None;
+) {
     for location in changes.iter() {
-        let stream = build_stream(source_files.lookup(location).unwrap());
-        if stream.contains_root() {
-            root_location = Some(location.clone());
-        } 
+        let source_text = match source_files.lookup(location) {
+            Some(source_text) => source_text,
+            None => {
+                stream_lookup.remove(location);
+                continue;
+            }
+        };
+        let stream = build_stream(location, source_text, controls);
         stream_lookup.insert(location.clone(), stream);       
     }
-    root_location
 }
 
-fn build_stream(source_text: &str) -> AbstractSyntaxTokenStream {
-    let source_tokenizer = SourceTokenizer::from_string(source_text);
+fn build_stream(location: &SourceLocation, source_text: &str, controls: &RegisteredControls) -> AbstractSyntaxTokenStream {
+    let source_tokenizer = SourceTokenizer::from_string(source_text).with_location(location.clone());
     let navigator = SourceTokenVisitationNavigator::from_source(source_tokenizer);
-    let mut ast_build_visitor = create_ast_token_visitor();
+    let mut ast_build_visitor = create_ast_token_visitor(controls);
     
     navigator.accept(&mut ast_build_visitor);
     ast_build_visitor.ast()        
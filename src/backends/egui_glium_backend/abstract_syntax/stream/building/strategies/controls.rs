@@ -4,15 +4,15 @@ use crate::prelude::*;
 pub struct ControlBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for ControlBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(AbstractSyntaxControlType::Empty);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::Empty, span);
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(AbstractSyntaxControlType::Empty);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::Empty, span);
     }
 
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -20,38 +20,48 @@ impl BuildAbstractSyntaxTokenStreamStrategy for ControlBuildAbstractSyntaxTokenS
         _property: &CurrentProperty, 
         _property_value: &SourceTokenPropertyValue, 
         _ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        _span: &SourceSpan
     ) {
     }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
+    }
 }
 
 #[derive(Default)]
-pub struct ControlReferenceBuildAbstractSyntaxTokenStreamStrategy(pub String);
+pub struct ControlReferenceBuildAbstractSyntaxTokenStreamStrategy(pub String, pub Vec<String>);
 
 impl BuildAbstractSyntaxTokenStreamStrategy for ControlReferenceBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports) {
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports, span: &SourceSpan) {
         if let Some(path) = imports.get_path(&self.0) {
-            ast.start_node(AbstractSyntaxControlType::Control);
+            ast.start_node(AbstractSyntaxControlType::Control, span);
             ast.property(create_ast_property(
                 AbstractSyntaxPropertyType::Name, 
-                AbstractSyntaxPropertyValue::String(self.0.clone())));
+                AbstractSyntaxPropertyValue::String(self.0.clone())), span);
             ast.property(create_ast_property(
                 AbstractSyntaxPropertyType::Path, 
-                AbstractSyntaxPropertyValue::String(path.clone())));
-            } else {
-            ast.start_node(AbstractSyntaxControlType::Unknown);
+                AbstractSyntaxPropertyValue::String(path.clone())), span);
+        } else {
+            let message = format!("unknown control {:?}", self.0);
+            let candidates = imports.names()
+                .chain(self.1.iter().map(String::as_str))
+                .chain(control_names().map(|name| -> &str { name }));
+            let suggestion = closest_match(&self.0, candidates);
+            ast.add_warning(Diagnostic::warning(with_suggestion(message, suggestion)).with_span(span));
+            ast.start_node(AbstractSyntaxControlType::Unknown, span);
         }
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports) {
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports, span: &SourceSpan) {
         if imports.get_path(&self.0).is_some() {
-            ast.end_node(AbstractSyntaxControlType::Control);
+            ast.end_node(AbstractSyntaxControlType::Control, span);
         } else {
-            ast.end_node(AbstractSyntaxControlType::Unknown);
+            ast.end_node(AbstractSyntaxControlType::Unknown, span);
         }
     }
 
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -59,8 +69,12 @@ impl BuildAbstractSyntaxTokenStreamStrategy for ControlReferenceBuildAbstractSyn
         _property: &CurrentProperty, 
         _property_value: &SourceTokenPropertyValue, 
         _ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        _span: &SourceSpan
     ) {
     }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
+    }
 }
 
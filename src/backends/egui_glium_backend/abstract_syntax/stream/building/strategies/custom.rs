@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+pub struct CustomBuildAbstractSyntaxTokenStreamStrategy {
+    name: String,
+    schema: ControlSchema
+}
+
+impl CustomBuildAbstractSyntaxTokenStreamStrategy {
+    pub fn new(control: &CustomControl) -> Self {
+        Self {
+            name: control.name().to_string(),
+            schema: control.schema().clone()
+        }
+    }
+
+    fn validated_property(&self, property_name: &str, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        match self.schema.validate(property_name, &property) {
+            Ok(()) => ast.property(property, span),
+            Err(warning) => ast.add_warning(Diagnostic::warning(warning).with_span(span))
+        }
+    }
+
+    fn unknown_property(&self, property_name: &str, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        let message = format!("unknown property {:?}", property_name);
+        let suggestion = closest_match(property_name, self.schema.property_names());
+        ast.add_warning(Diagnostic::warning(with_suggestion(message, suggestion)).with_span(span))
+    }
+}
+
+impl BuildAbstractSyntaxTokenStreamStrategy for CustomBuildAbstractSyntaxTokenStreamStrategy {
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::Custom, span);
+        ast.property(create_ast_property(
+            AbstractSyntaxPropertyType::Name,
+            AbstractSyntaxPropertyValue::String(self.name.clone())), span);
+    }
+
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::Custom, span);
+    }
+
+    fn property(&self, property: &CurrentProperty, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        if let CurrentProperty::Standard(property_name) = property {
+            match self.schema.find(property_name) {
+                Some(schema) if schema.value_type() == PropertyValueType::Bool =>
+                    ast.property(create_ast_property(schema.property_type().clone(), AbstractSyntaxPropertyValue::Bool(true)), span),
+                Some(schema) => ast.add_warning(Diagnostic::warning(
+                    format!("property {:?} expects a {:?} value", property_name, schema.value_type())
+                ).with_span(span)),
+                None => self.unknown_property(property_name, ast, span)
+            }
+        }
+    }
+
+    fn property_value(
+        &self, 
+        property: &CurrentProperty, 
+        property_value: &SourceTokenPropertyValue, 
+        ast: &mut AbstractSyntaxTokenStream, 
+        _imports: &mut SourceImports,
+        span: &SourceSpan
+    ) {
+        match property {
+            CurrentProperty::None => {},
+            CurrentProperty::Standard(property_name) => match self.schema.find(property_name) {
+                Some(schema) => match match_custom_property_value(schema, property_value) {
+                    Ok(property) => self.validated_property(property_name, property, ast, span),
+                    Err(error) => ast.property_error(error, span)
+                },
+                None => self.unknown_property(property_name, ast, span)
+            },
+            CurrentProperty::Variable(variable_name) =>
+                ast.property_error(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string()), span),
+        }
+    }
+
+    fn text(&self, text: &str, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        let schema = match self.schema.find("text") {
+            Some(schema) => schema,
+            None => return ast.add_warning(Diagnostic::warning(format!("text is not supported by {:?}", self.name)).with_span(span))
+        };
+        match match_text_value(text) {
+            Ok(property) => ast.property(create_ast_property(schema.property_type().clone(), property.value().clone()), span),
+            Err(error) => ast.property_error(error, span),
+        }
+    }
+}
\ No newline at end of file
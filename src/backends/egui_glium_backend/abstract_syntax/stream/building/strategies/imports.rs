@@ -4,15 +4,15 @@ use crate::prelude::*;
 pub struct ImportBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for ImportBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(AbstractSyntaxControlType::Empty);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::Empty, span);
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(AbstractSyntaxControlType::Empty);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::Empty, span);
     }
 
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -20,19 +20,23 @@ impl BuildAbstractSyntaxTokenStreamStrategy for ImportBuildAbstractSyntaxTokenSt
         property: &CurrentProperty, 
         property_value: &SourceTokenPropertyValue, 
         ast: &mut AbstractSyntaxTokenStream, 
-        imports: &mut SourceImports
+        imports: &mut SourceImports,
+        span: &SourceSpan
     ) {
         match property {
             CurrentProperty::None => {},
             CurrentProperty::Standard(current_property_name) => {
                 if let Err(error) = store_import(current_property_name, property_value, imports) {
-                    ast.property_error(error);
+                    ast.property_error(error, span);
                 }
             },
             CurrentProperty::Variable(variable_name) =>
-                ast.property_error(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string())),
+                ast.property_error(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string()), span),
         }
     }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
+    }
 }
 
 fn store_import(
@@ -3,11 +3,11 @@ use crate::prelude::*;
 pub struct ForEachBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for ForEachBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(AbstractSyntaxControlType::ForEach);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::ForEach, span);
     }
     
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -15,31 +15,35 @@ impl BuildAbstractSyntaxTokenStreamStrategy for ForEachBuildAbstractSyntaxTokenS
         property: &CurrentProperty, 
         property_value: &SourceTokenPropertyValue, 
         ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        span: &SourceSpan
     ) {
         match property {
             CurrentProperty::None => {},
-            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string())),
+            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string()), span),
             CurrentProperty::Variable(variable_name) => match match_for_each_property_value(variable_name, property_value) {
-                Ok(property) => ast.property(property),
-                Err(error) => ast.property_error(error)
+                Ok(property) => ast.property(property, span),
+                Err(error) => ast.property_error(error, span)
             },
         }
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(AbstractSyntaxControlType::For);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::For, span);
+    }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 }
 
 pub struct ForBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for ForBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(AbstractSyntaxControlType::For);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::For, span);
     }
     
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -47,20 +51,24 @@ impl BuildAbstractSyntaxTokenStreamStrategy for ForBuildAbstractSyntaxTokenStrea
         property: &CurrentProperty, 
         property_value: &SourceTokenPropertyValue, 
         ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        span: &SourceSpan
     ) {
         match property {
             CurrentProperty::None => {},
-            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string())),
+            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string()), span),
             CurrentProperty::Variable(variable_name) => match match_for_property_value(variable_name, property_value) {
-                Ok(property) => ast.property(property),
-                Err(error) => ast.property_error(error)
+                Ok(property) => ast.property(property, span),
+                Err(error) => ast.property_error(error, span)
             },
         }
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(AbstractSyntaxControlType::For);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::For, span);
+    }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 }
 
@@ -79,8 +87,8 @@ fn match_for_property_value(variable_name: &str, property_value: &SourceTokenPro
     match property_value {
         SourceTokenPropertyValue::Array(token_result) => 
         Ok(create_ast_property(
-            AbstractSyntaxPropertyType::USizeRangeVariable, 
-            AbstractSyntaxPropertyValue::USizeRangeVariable(variable_name.to_string(), USizeRange::parse(token_result)?)
+            AbstractSyntaxPropertyType::ArrayVariable, 
+            AbstractSyntaxPropertyValue::ArrayVariable(variable_name.to_string(), AbstractSyntaxPropertyValue::parse_array(token_result)?)
         )),
         _ => Err(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string())) 
     }
@@ -1,11 +1,13 @@
 mod imports;
 mod controls;
+mod custom;
 mod standard;
 mod looping;
 mod variable_assigment;
 
 pub use imports::*;
 pub use controls::*;
+pub use custom::*;
 pub use standard::*;
 pub use looping::*;
 pub use variable_assigment::*;
@@ -13,27 +15,26 @@ pub use variable_assigment::*;
 use crate::prelude::*;
 
 pub trait BuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports);
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports);
-    fn property(&self, property: &CurrentProperty, ast: &mut AbstractSyntaxTokenStream);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports, span: &SourceSpan);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, imports: &SourceImports, span: &SourceSpan);
+    fn property(&self, property: &CurrentProperty, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan);
     fn property_value(
         &self,
         property: &CurrentProperty,
         property_value: &SourceTokenPropertyValue,
         ast: &mut AbstractSyntaxTokenStream,
-        imports: &mut SourceImports);
-    
+        imports: &mut SourceImports,
+        span: &SourceSpan);
+    fn text(&self, text: &str, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan);
 }
 
 pub struct EmptyBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for EmptyBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, _ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        panic!()
+    fn control(&self, _ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, _span: &SourceSpan) {
     }
 
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
-        panic!()
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -41,12 +42,14 @@ impl BuildAbstractSyntaxTokenStreamStrategy for EmptyBuildAbstractSyntaxTokenStr
         _property: &CurrentProperty, 
         _property_value: &SourceTokenPropertyValue, 
         _ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        _span: &SourceSpan
     ) {
-        panic!()
     }
 
-    fn end_control(&self, _ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        panic!()
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
+    }
+
+    fn end_control(&self, _ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, _span: &SourceSpan) {
     }
 }
\ No newline at end of file
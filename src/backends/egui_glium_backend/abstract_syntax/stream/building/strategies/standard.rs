@@ -1,18 +1,45 @@
 use crate::prelude::*;
 
-pub struct StandardBuildAbstractSyntaxTokenStreamStrategy(pub AbstractSyntaxControlType);
+pub struct StandardBuildAbstractSyntaxTokenStreamStrategy {
+    control_type: AbstractSyntaxControlType,
+    schema: ControlSchema
+}
+
+impl StandardBuildAbstractSyntaxTokenStreamStrategy {
+    pub fn new(control_type: AbstractSyntaxControlType) -> Self {
+        Self {
+            control_type,
+            schema: control_schema(control_type)
+        }
+    }
+
+    fn validated_property(&self, property_name: &str, property: AbstractSyntaxProperty, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        match self.schema.validate(property_name, &property) {
+            Ok(()) => ast.property(property, span),
+            Err(warning) => ast.add_warning(Diagnostic::warning(warning).with_span(span))
+        }
+    }
+}
 
 impl BuildAbstractSyntaxTokenStreamStrategy for StandardBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(self.0);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(self.control_type, span);
     }
 
-    fn property(&self, property: &CurrentProperty, ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, property: &CurrentProperty, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
         match property {
             CurrentProperty::None => {},
             CurrentProperty::Standard(property_name) => {
-                if let Some(property) = match_property_only(&property_name) {
-                    ast.property(property);
+                match (match_property_only(property_name), self.schema.find(property_name)) {
+                    (Some(property), _) => self.validated_property(property_name, property, ast, span),
+                    (None, Some(schema)) => ast.add_warning(Diagnostic::warning(
+                        format!("property {:?} expects a {:?} value", property_name, schema.value_type())
+                    ).with_span(span)),
+                    (None, None) => {
+                        let message = format!("unknown property {:?}", property_name);
+                        let suggestion = closest_match(property_name, self.schema.property_names());
+                        ast.add_warning(Diagnostic::warning(with_suggestion(message, suggestion)).with_span(span))
+                    }
                 }
             },
             CurrentProperty::Variable(_) => {}
@@ -24,27 +51,57 @@ impl BuildAbstractSyntaxTokenStreamStrategy for StandardBuildAbstractSyntaxToken
         property: &CurrentProperty, 
         property_value: &SourceTokenPropertyValue, 
         ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        span: &SourceSpan
     ) {
         match property {
             CurrentProperty::None => {},
             CurrentProperty::Standard(current_property_name) => {
                 match match_property_value(&current_property_name, property_value) {
-                    Ok(property) => ast.property(property),
-                    Err(error) => ast.property_error(error),
+                    Ok(property) => self.validated_property(current_property_name, property, ast, span),
+                    Err(AbstractSyntaxTokenError::UnknownProperty(property_name)) => {
+                        let message = format!("unknown property {:?}", property_name);
+                        let suggestion = closest_match(&property_name, self.schema.property_names());
+                        ast.add_warning(Diagnostic::warning(with_suggestion(message, suggestion)).with_span(span))
+                    },
+                    Err(error) => ast.property_error(error, span),
                 }
             },
             CurrentProperty::Variable(variable_name) =>
-                ast.property_error(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string())),
+                ast.property_error(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string()), span),
+        }
+    }
+
+    fn text(&self, text: &str, ast: &mut AbstractSyntaxTokenStream, span: &SourceSpan) {
+        match match_text_value(text) {
+            Ok(property) => self.validated_property("text", property, ast, span),
+            Err(error) => ast.property_error(error, span),
         }
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(self.0);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(self.control_type, span);
     }
 }
 
+pub fn match_text_value(text: &str) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    let interpolated_text = InterpolatedText::parse(text)?;
+    if interpolated_text.has_variables() {
+        return Ok(create_ast_property(
+            AbstractSyntaxPropertyType::Text, 
+            AbstractSyntaxPropertyValue::InterpolatedText(interpolated_text)
+        ));
+    }
+    Ok(create_ast_property(
+        AbstractSyntaxPropertyType::Text, 
+        AbstractSyntaxPropertyValue::String(interpolated_text.to_string())
+    ))
+}
+
 fn match_property_value(property_name: &str, property_value: &SourceTokenPropertyValue) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    if let Some(property_type) = match_bool_property_type(property_name) {
+        return match_bool_property_value(property_name, property_type, property_value);
+    }
     match property_name {
         "id" => {
             match property_value {
@@ -78,6 +135,10 @@ fn match_property_value(property_name: &str, property_value: &SourceTokenPropert
                     AbstractSyntaxPropertyType::Text, 
                     AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
                 )),
+                SourceTokenPropertyValue::Code(value) => Ok(create_ast_property(
+                    AbstractSyntaxPropertyType::Text, 
+                    AbstractSyntaxPropertyValue::parse_code(value)?
+                )),
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
             }
         },
@@ -94,69 +155,24 @@ fn match_property_value(property_name: &str, property_value: &SourceTokenPropert
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
             }
         },
-        "default-width" => {
-            match property_value {
-                SourceTokenPropertyValue::Float(value) => Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::DefaultWidth, 
-                    AbstractSyntaxPropertyValue::Float(*value as f32)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
-        "default-height" => {
-            match property_value {
-                SourceTokenPropertyValue::Float(value) => Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::DefaultHeight, 
-                    AbstractSyntaxPropertyValue::Float(*value as f32)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
+        "default-width" => match_size_property_value(property_name, AbstractSyntaxPropertyType::DefaultWidth, property_value),
+        "default-height" => match_size_property_value(property_name, AbstractSyntaxPropertyType::DefaultHeight, property_value),
         "height-range" => {
             match property_value {
                 SourceTokenPropertyValue::Array(value) => 
-                Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::HeightRange, 
-                    AbstractSyntaxPropertyValue::FloatRange(FloatRange::parse(value)?)
-                )),
+                    match_array_property_value(AbstractSyntaxPropertyType::HeightRange, value, |value| Ok(AbstractSyntaxPropertyValue::SizeRange(SizeRange::parse(value)?))),
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
             }
         },
         "width-range" => {
             match property_value {
                 SourceTokenPropertyValue::Array(value) => 
-                Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::WidthRange, 
-                    AbstractSyntaxPropertyValue::FloatRange(FloatRange::parse(value)?)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
-        "colour" => {
-            match property_value {
-                SourceTokenPropertyValue::Array(value) => 
-                    Ok(create_ast_property(
-                        AbstractSyntaxPropertyType::Colour, 
-                        AbstractSyntaxPropertyValue::Colour(Colour::parse(value)?)
-                    )
-                ),
-                SourceTokenPropertyValue::Variable(value) => Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::Colour, 
-                    AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
-        "background-colour" => {
-            match property_value {
-                SourceTokenPropertyValue::Array(value) => 
-                Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::BackgroundColour, 
-                    AbstractSyntaxPropertyValue::Colour(Colour::parse(value)?)
-                )),
+                    match_array_property_value(AbstractSyntaxPropertyType::WidthRange, value, |value| Ok(AbstractSyntaxPropertyValue::SizeRange(SizeRange::parse(value)?))),
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
             }
         },
+        "colour" => match_colour_property_value(property_name, AbstractSyntaxPropertyType::Colour, property_value),
+        "background-colour" => match_colour_property_value(property_name, AbstractSyntaxPropertyType::BackgroundColour, property_value),
         "text-style" => {
             match property_value {
                 SourceTokenPropertyValue::String(value) =>
@@ -167,16 +183,7 @@ fn match_property_value(property_name: &str, property_value: &SourceTokenPropert
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string()))
             }
         },
-        "max-height" => {
-            match property_value {
-                SourceTokenPropertyValue::Float(value) => 
-                Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::VerticallySized, 
-                    AbstractSyntaxPropertyValue::Float(*value as f32)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
+        "max-height" => match_size_property_value(property_name, AbstractSyntaxPropertyType::VerticallySized, property_value),
         "scroll_offset" => {
             match property_value {
                 SourceTokenPropertyValue::Float(value) => Ok(create_ast_property(
@@ -195,34 +202,156 @@ fn match_property_value(property_name: &str, property_value: &SourceTokenPropert
                 _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
             }
         },
-        "selected" => {
-            match property_value {
-                SourceTokenPropertyValue::Variable(value) => Ok(create_ast_property(
-                    AbstractSyntaxPropertyType::Selected, 
-                    AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
-                )),
-                _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
-            }
-        },
         _ => Err(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string())) 
     }
 }
 
+pub fn match_custom_property_value(
+    schema: &PropertySchema,
+    property_value: &SourceTokenPropertyValue
+) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    let property_name = schema.name();
+    let property_type = schema.property_type().clone();
+    match (schema.value_type(), property_value) {
+        (PropertyValueType::Bool, _) => match_bool_property_value(property_name, property_type, property_value),
+        (PropertyValueType::Size, _) => match_size_property_value(property_name, property_type, property_value),
+        (PropertyValueType::Colour, _) => match_colour_property_value(property_name, property_type, property_value),
+        (PropertyValueType::SizeRange, SourceTokenPropertyValue::Array(value)) =>
+            match_array_property_value(property_type, value, |value| Ok(AbstractSyntaxPropertyValue::SizeRange(SizeRange::parse(value)?))),
+        (_, SourceTokenPropertyValue::String(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::String(value.clone())
+        )),
+        (_, SourceTokenPropertyValue::Float(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Float(*value as f32)
+        )),
+        (_, SourceTokenPropertyValue::USize(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Float(*value as f32)
+        )),
+        (_, SourceTokenPropertyValue::Variable(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
+        )),
+        (PropertyValueType::Function, SourceTokenPropertyValue::Code(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Function(Function::parse(value)?)
+        )),
+        (_, SourceTokenPropertyValue::Code(value)) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::parse_code(value)?
+        )),
+        _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
+    }
+}
+
+fn match_bool_property_value(
+    property_name: &str,
+    property_type: AbstractSyntaxPropertyType,
+    property_value: &SourceTokenPropertyValue
+) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    match property_value {
+        SourceTokenPropertyValue::Bool(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Bool(*value)
+        )),
+        SourceTokenPropertyValue::Variable(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
+        )),
+        SourceTokenPropertyValue::Code(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::parse_code(value)?
+        )),
+        _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
+    }
+}
+
+fn match_array_property_value(
+    property_type: AbstractSyntaxPropertyType,
+    property_value: &Vec<ArrayTokenResult>,
+    parse_literal: fn(&Vec<ArrayTokenResult>) -> Result<AbstractSyntaxPropertyValue, AbstractSyntaxTokenError>
+) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    if AbstractSyntaxPropertyValue::has_dynamic_array_items(property_value) {
+        return Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Array(AbstractSyntaxPropertyValue::parse_array(property_value)?)
+        ));
+    }
+    Ok(create_ast_property(property_type, parse_literal(property_value)?))
+}
+
+fn match_size_property_value(
+    property_name: &str,
+    property_type: AbstractSyntaxPropertyType,
+    property_value: &SourceTokenPropertyValue
+) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    match property_value {
+        SourceTokenPropertyValue::Float(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Size(Size::points(*value as f32))
+        )),
+        SourceTokenPropertyValue::USize(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Size(Size::points(*value as f32))
+        )),
+        SourceTokenPropertyValue::Size(value, unit) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Size(Size::new(*value as f32, *unit))
+        )),
+        _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
+    }
+}
+
+fn match_colour_property_value(
+    property_name: &str,
+    property_type: AbstractSyntaxPropertyType,
+    property_value: &SourceTokenPropertyValue
+) -> Result<AbstractSyntaxProperty, AbstractSyntaxTokenError> {
+    match property_value {
+        SourceTokenPropertyValue::Array(value) => 
+            match_array_property_value(property_type, value, |value| Ok(AbstractSyntaxPropertyValue::Colour(Colour::parse(value)?))),
+        SourceTokenPropertyValue::Colour(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Colour((*value).into())
+        )),
+        SourceTokenPropertyValue::String(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::Colour(Colour::parse_literal(value)?)
+        )),
+        SourceTokenPropertyValue::Variable(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse(value.clone())?)
+        )),
+        SourceTokenPropertyValue::Code(value) => Ok(create_ast_property(
+            property_type, 
+            AbstractSyntaxPropertyValue::parse_code(value)?
+        )),
+        _ => Err(AbstractSyntaxTokenError::UnknownPropertyValue(property_name.to_string())) 
+    }
+}
+
 fn match_property_only(property_name: &str) -> Option<AbstractSyntaxProperty> {
+    match_bool_property_type(property_name)
+        .map(|property_type| create_ast_property(property_type, AbstractSyntaxPropertyValue::Bool(true)))
+}
+
+fn match_bool_property_type(property_name: &str) -> Option<AbstractSyntaxPropertyType> {
     match property_name {
-        "selected" => Some(create_ast_property(AbstractSyntaxPropertyType::Selected, AbstractSyntaxPropertyValue::Bool(true))),
-        "resizable" => Some(create_ast_property(AbstractSyntaxPropertyType::Resizable, AbstractSyntaxPropertyValue::Bool(true))),
-        "wrap" => Some(create_ast_property(AbstractSyntaxPropertyType::Wrap, AbstractSyntaxPropertyValue::Bool(true))),
-        "code" => Some(create_ast_property(AbstractSyntaxPropertyType::Code, AbstractSyntaxPropertyValue::Bool(true))),
-        "strong" => Some(create_ast_property(AbstractSyntaxPropertyType::Strong, AbstractSyntaxPropertyValue::Bool(true))),
-        "weak" => Some(create_ast_property(AbstractSyntaxPropertyType::Weak, AbstractSyntaxPropertyValue::Bool(true))),
-        "strike-through" => Some(create_ast_property(AbstractSyntaxPropertyType::Strikethrough, AbstractSyntaxPropertyValue::Bool(true))),
-        "underline" => Some(create_ast_property(AbstractSyntaxPropertyType::Underline, AbstractSyntaxPropertyValue::Bool(true))),
-        "italics" => Some(create_ast_property(AbstractSyntaxPropertyType::Italics, AbstractSyntaxPropertyValue::Bool(true))),
-        "raised" => Some(create_ast_property(AbstractSyntaxPropertyType::Raised, AbstractSyntaxPropertyValue::Bool(true))),
-        "auto-sized" => Some(create_ast_property(AbstractSyntaxPropertyType::AutoSized, AbstractSyntaxPropertyValue::Bool(true))),
-        "always_show_scroll" => Some(create_ast_property(AbstractSyntaxPropertyType::AlwaysShowScroll, AbstractSyntaxPropertyValue::Bool(true))),
-        "enable_scrolling" => Some(create_ast_property(AbstractSyntaxPropertyType::EnableScrolling, AbstractSyntaxPropertyValue::Bool(true))),
+        "selected" => Some(AbstractSyntaxPropertyType::Selected),
+        "resizable" => Some(AbstractSyntaxPropertyType::Resizable),
+        "wrap" => Some(AbstractSyntaxPropertyType::Wrap),
+        "code" => Some(AbstractSyntaxPropertyType::Code),
+        "strong" => Some(AbstractSyntaxPropertyType::Strong),
+        "weak" => Some(AbstractSyntaxPropertyType::Weak),
+        "strike-through" => Some(AbstractSyntaxPropertyType::Strikethrough),
+        "underline" => Some(AbstractSyntaxPropertyType::Underline),
+        "italics" => Some(AbstractSyntaxPropertyType::Italics),
+        "raised" => Some(AbstractSyntaxPropertyType::Raised),
+        "auto-sized" => Some(AbstractSyntaxPropertyType::AutoSized),
+        "always_show_scroll" => Some(AbstractSyntaxPropertyType::AlwaysShowScroll),
+        "enable_scrolling" => Some(AbstractSyntaxPropertyType::EnableScrolling),
         _ => None 
     }
 }
\ No newline at end of file
@@ -3,11 +3,11 @@ use crate::prelude::*;
 pub struct LetBuildAbstractSyntaxTokenStreamStrategy;
 
 impl BuildAbstractSyntaxTokenStreamStrategy for LetBuildAbstractSyntaxTokenStreamStrategy {
-    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.start_node(AbstractSyntaxControlType::Let);
+    fn control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.start_node(AbstractSyntaxControlType::Let, span);
     }
     
-    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream) {
+    fn property(&self, _property: &CurrentProperty, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 
     fn property_value(
@@ -15,20 +15,24 @@ impl BuildAbstractSyntaxTokenStreamStrategy for LetBuildAbstractSyntaxTokenStrea
         property: &CurrentProperty, 
         property_value: &SourceTokenPropertyValue, 
         ast: &mut AbstractSyntaxTokenStream, 
-        _imports: &mut SourceImports
+        _imports: &mut SourceImports,
+        span: &SourceSpan
     ) {
         match property {
             CurrentProperty::None => {},
-            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string())),
+            CurrentProperty::Standard(property_name) => ast.property_error(AbstractSyntaxTokenError::UnknownProperty(property_name.to_string()), span),
             CurrentProperty::Variable(variable_name) => match match_property_value(variable_name, property_value) {
-                Ok(property) => ast.property(property),
-                Err(error) => ast.property_error(error)
+                Ok(property) => ast.property(property, span),
+                Err(error) => ast.property_error(error, span)
             },
         }
     }
 
-    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports) {
-        ast.end_node(AbstractSyntaxControlType::Let);
+    fn end_control(&self, ast: &mut AbstractSyntaxTokenStream, _imports: &SourceImports, span: &SourceSpan) {
+        ast.end_node(AbstractSyntaxControlType::Let, span);
+    }
+
+    fn text(&self, _text: &str, _ast: &mut AbstractSyntaxTokenStream, _span: &SourceSpan) {
     }
 }
 
@@ -36,8 +40,8 @@ fn match_property_value(variable_name: &str, property_value: &SourceTokenPropert
     match property_value {
         SourceTokenPropertyValue::Code(tokens) => 
         Ok(create_ast_property(
-            AbstractSyntaxPropertyType::FunctionVariable, 
-            AbstractSyntaxPropertyValue::FunctionVariable(variable_name.to_string(), Function::parse(tokens)?)
+            AbstractSyntaxPropertyType::ExpressionVariable, 
+            AbstractSyntaxPropertyValue::ExpressionVariable(variable_name.to_string(), Box::new(Expression::parse(tokens)?))
         )),
         _ => Err(AbstractSyntaxTokenError::UnknownProperty(variable_name.to_string())) 
     }
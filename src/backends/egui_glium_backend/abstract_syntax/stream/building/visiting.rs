@@ -7,85 +7,140 @@ pub enum CurrentProperty {
     Variable(String)
 }
 
-pub fn create_ast_token_visitor() -> BuildAbstractSyntaxSourceTokenVisitor {
-    BuildAbstractSyntaxSourceTokenVisitor::new()
+pub fn create_ast_token_visitor(controls: &RegisteredControls) -> BuildAbstractSyntaxSourceTokenVisitor<'_> {
+    BuildAbstractSyntaxSourceTokenVisitor::new(controls)
 }
 
-pub struct BuildAbstractSyntaxSourceTokenVisitor {
+struct ControlStrategy {
+    strategy: Box<dyn BuildAbstractSyntaxTokenStreamStrategy + 'static>,
+    text: Option<(String, SourceSpan)>
+}
+
+impl ControlStrategy {
+    fn new(strategy: Box<dyn BuildAbstractSyntaxTokenStreamStrategy + 'static>) -> Self {
+        Self {
+            strategy,
+            text: None
+        }
+    }
+
+    fn append_text(&mut self, text: &str, span: &SourceSpan) {
+        match &mut self.text {
+            Some((current_text, _)) => {
+                current_text.push(' ');
+                current_text.push_str(text);
+            },
+            None => self.text = Some((text.to_string(), span.clone()))
+        }
+    }
+}
+
+pub struct BuildAbstractSyntaxSourceTokenVisitor<'a> {
     pub imports: SourceImports,
     pub ast: AbstractSyntaxTokenStream,
     pub current_property: CurrentProperty,
-    current_property_strategy: Box<dyn BuildAbstractSyntaxTokenStreamStrategy + 'static>
+    pending_property: Option<SourceSpan>,
+    controls: &'a RegisteredControls,
+    control_strategies: Vec<ControlStrategy>
 }
 
 
-impl BuildAbstractSyntaxSourceTokenVisitor {
-    fn new() -> Self {
+impl<'a> BuildAbstractSyntaxSourceTokenVisitor<'a> {
+    fn new(controls: &'a RegisteredControls) -> Self {
         Self {
             imports: SourceImports::default(),
             ast: AbstractSyntaxTokenStream::default(),
             current_property: CurrentProperty::None,
-            current_property_strategy: Box::new(EmptyBuildAbstractSyntaxTokenStreamStrategy)
+            pending_property: None,
+            controls,
+            control_strategies: vec!(ControlStrategy::new(Box::new(EmptyBuildAbstractSyntaxTokenStreamStrategy)))
         }
     }
 
-    pub fn ast(self) -> AbstractSyntaxTokenStream {
+    pub fn ast(mut self) -> AbstractSyntaxTokenStream {
+        self.flush_pending_property();
         self.ast
-    }   
+    }
+
+    fn flush_pending_property(&mut self) {
+        if let Some(span) = self.pending_property.take() {
+            if let Some(control_strategy) = self.control_strategies.last() {
+                control_strategy.strategy.property(&self.current_property, &mut self.ast, &span);
+            }
+        }
+    }
 
     fn match_control_name(&mut self, control_name: &str) -> Box<dyn BuildAbstractSyntaxTokenStreamStrategy> {
         match control_name {
-            "root" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Root)),
             "control" => Box::new(ControlBuildAbstractSyntaxTokenStreamStrategy::default()),
             "import" => Box::new(ImportBuildAbstractSyntaxTokenStreamStrategy::default()),
             "for" => Box::new(ForBuildAbstractSyntaxTokenStreamStrategy),
             "for-each" => Box::new(ForEachBuildAbstractSyntaxTokenStreamStrategy),
             "let" => Box::new(LetBuildAbstractSyntaxTokenStreamStrategy),
-            "central-panel" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::CentralPanel)),
-            "top-panel" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::TopPanel)),
-            "bottom-panel" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::BottomPanel)),
-            "left-side-bar" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::LeftSidebar)),
-            "right-side-bar" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::RightSidebar)),
-            "scroll-area" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::ScrollArea)),
-            "separator" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Separator)),
-            "horizontal" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Horizontal)),
-            "vertical" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Vertical)),
-            "label" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Label)),
-            "coloured-label" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::ColouredLabel)),
-            "selectable-label" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::SelectableLabel)),
-            "heading" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Heading)),
-            "monospace" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Monospace)),
-            "code" => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy(AbstractSyntaxControlType::Code)),
-            name => Box::new(ControlReferenceBuildAbstractSyntaxTokenStreamStrategy(name.to_string()))
+            name => match (standard_control_type(name), self.controls.get_control(name)) {
+                (Some(control_type), _) => Box::new(StandardBuildAbstractSyntaxTokenStreamStrategy::new(control_type)),
+                (None, Some(control)) => Box::new(CustomBuildAbstractSyntaxTokenStreamStrategy::new(control)),
+                (None, None) => Box::new(ControlReferenceBuildAbstractSyntaxTokenStreamStrategy(
+                    name.to_string(),
+                    self.controls.control_names().map(String::from).collect()
+                ))
+            }
         }
     }
 }
 
-impl SourceTokenVisitor for BuildAbstractSyntaxSourceTokenVisitor {
+impl<'a> SourceTokenVisitor for BuildAbstractSyntaxSourceTokenVisitor<'a> {
     fn token_error(&mut self, error: SourceTokenError) {
-        self.ast.add_error(AbstractSyntaxTokenError::SourceTokenError(error))
+        self.pending_property = None;
+        let span = error.span().clone();
+        self.ast.add_error(AbstractSyntaxTokenError::SourceTokenError(error), &span)
     }
 
-    fn control(&mut self, control_name: &str) {
-        self.current_property_strategy = self.match_control_name(control_name);
-        self.current_property_strategy.control(&mut self.ast, &self.imports);
+    fn control(&mut self, control_name: &str, span: &SourceSpan) {
+        self.flush_pending_property();
+        let strategy = self.match_control_name(control_name);
+        strategy.control(&mut self.ast, &self.imports, span);
+        self.control_strategies.push(ControlStrategy::new(strategy));
     }
 
-    fn property(&mut self, property_name: &str) {
+    fn property(&mut self, property_name: &str, span: &SourceSpan) {
+        self.flush_pending_property();
         self.current_property = CurrentProperty::Standard(property_name.to_string());
-        self.current_property_strategy.property(&self.current_property, &mut self.ast);
+        self.pending_property = Some(span.clone());
     }
 
-    fn variable_property(&mut self, variable_name: &str) {
+    fn variable_property(&mut self, variable_name: &str, span: &SourceSpan) {
+        self.flush_pending_property();
         self.current_property = CurrentProperty::Variable(variable_name.to_string());
-        self.current_property_strategy.property(&self.current_property, &mut self.ast);
+        self.pending_property = Some(span.clone());
+    }
+
+    fn property_value(&mut self, property_value: &SourceTokenPropertyValue, span: &SourceSpan) {
+        self.pending_property = None;
+        if let Some(control_strategy) = self.control_strategies.last() {
+            control_strategy.strategy.property_value(&self.current_property, property_value, &mut self.ast, &mut self.imports, span);
+        }
     }
 
-    fn property_value(&mut self, property_value: &SourceTokenPropertyValue) {
-        self.current_property_strategy.property_value(&self.current_property, property_value, &mut self.ast, &mut self.imports);
+    fn text(&mut self, text: &str, span: &SourceSpan) {
+        self.flush_pending_property();
+        if let Some(control_strategy) = self.control_strategies.last_mut() {
+            control_strategy.append_text(text, span);
+        }
     }
     
-    fn end_control(&mut self, _control_name: &str) {
-        self.current_property_strategy.end_control(&mut self.ast, &self.imports);
+    fn end_control(&mut self, control_name: &str, span: &SourceSpan) {
+        self.flush_pending_property();
+        if self.control_strategies.len() == 1 {
+            return self.ast.add_error(AbstractSyntaxTokenError::UnopenedControl(control_name.to_string()), span);
+        }
+        let control_strategy = match self.control_strategies.pop() {
+            Some(control_strategy) => control_strategy,
+            None => return
+        };
+        if let Some((text, text_span)) = &control_strategy.text {
+            control_strategy.strategy.text(text, &mut self.ast, text_span);
+        }
+        control_strategy.strategy.end_control(&mut self.ast, &self.imports, span);
     }
 }
\ No newline at end of file
@@ -1,41 +1,54 @@
 use crate::prelude::*;
 
 #[derive(Debug, Clone, Default)]
-pub struct AbstractSyntaxTokenStream(Vec<AbstractSyntaxTokenResult>, bool);
+pub struct AbstractSyntaxTokenStream(Vec<(AbstractSyntaxTokenResult, SourceSpan)>, Diagnostics);
 
-impl AbstractSyntaxTokenStream { 
+impl AbstractSyntaxTokenStream {
     pub fn append_stream(&mut self, control_stream: &mut AbstractSyntaxTokenStream) {
         self.0.append(&mut control_stream.0);
     }
 
-    pub fn add_error(&mut self, error: AbstractSyntaxTokenError) {
-        self.0.push(Err(error));
+    pub fn add_error(&mut self, error: AbstractSyntaxTokenError, span: &SourceSpan) {
+        self.0.push((Err(error), span.clone()));
     }
 
-    pub fn start_node(&mut self, node_type: AbstractSyntaxControlType) {
-        println!("{:?}", node_type);
-        if node_type == AbstractSyntaxControlType::Root {
-            self.1 = true;
-        }
-        self.0.push(Ok(AbstractSyntaxToken::StartControl(node_type)));
+    pub fn add_warning(&mut self, warning: Diagnostic) {
+        self.1.push(warning);
+    }
+
+    pub fn warnings(&self) -> &Diagnostics {
+        &self.1
+    }
+
+    pub fn start_node(&mut self, node_type: AbstractSyntaxControlType, span: &SourceSpan) {
+        debug!("{:?}", node_type);
+        self.0.push((Ok(AbstractSyntaxToken::StartControl(node_type)), span.clone()));
 
     }
 
-    pub fn property(&mut self, property: AbstractSyntaxProperty) {
-        println!("prop {:?}", property);
-        self.0.push(Ok(AbstractSyntaxToken::Property(property)));
+    pub fn property(&mut self, property: AbstractSyntaxProperty, span: &SourceSpan) {
+        debug!("prop {:?}", property);
+        self.0.push((Ok(AbstractSyntaxToken::Property(property)), span.clone()));
     }
 
-    pub fn property_error(&mut self, error: AbstractSyntaxTokenError) {
-        self.0.push(Err(error));
+    pub fn property_error(&mut self, error: AbstractSyntaxTokenError, span: &SourceSpan) {
+        self.0.push((Err(error), span.clone()));
     }
 
-    pub fn end_node(&mut self, node_type: AbstractSyntaxControlType) {
-        self.0.push(Ok(AbstractSyntaxToken::EndControl(node_type)));
+    pub fn end_node(&mut self, node_type: AbstractSyntaxControlType, span: &SourceSpan) {
+        self.0.push((Ok(AbstractSyntaxToken::EndControl(node_type)), span.clone()));
     }
 
-    pub fn contains_root(&self) -> bool {
-        self.1
+    pub fn root_name(&self) -> Option<String> {
+        let root = self.0.iter().position(|(token, _)| matches!(token, Ok(AbstractSyntaxToken::StartControl(AbstractSyntaxControlType::Root))))?;
+        let name = self.0[root + 1..].iter()
+            .map_while(|(token, _)| match token {
+                Ok(AbstractSyntaxToken::Property(property)) => Some(property),
+                _ => None
+            })
+            .find(|property| property.property_type() == &AbstractSyntaxPropertyType::Name)
+            .and_then(|property| property.value().get_string_value().ok());
+        Some(name.unwrap_or_else(|| DEFAULT_ROOT_NAME.to_string()))
     }
 
     pub fn accept(&self, visitor: &mut impl AbstractSyntaxTokenStreamVisitor, context: &mut DataContext) {
@@ -45,20 +58,20 @@ impl AbstractSyntaxTokenStream {
     }
 
     fn accept_node(&self, position: usize, visitor: &mut impl AbstractSyntaxTokenStreamVisitor, context: &mut DataContext) {
-        let node_result = &self.0[position];
+        let (node_result, span) = &self.0[position];
 
         match node_result {
             Ok(node) => match node {
-                AbstractSyntaxToken::StartControl(node_type) => visitor.start_node_with_repeat_possibility(position, node_type, context),
-                AbstractSyntaxToken::Property(property) => visitor.property(property, context),
+                AbstractSyntaxToken::StartControl(node_type) => visitor.start_node_with_repeat_possibility(position, node_type, span, context),
+                AbstractSyntaxToken::Property(property) => visitor.property(property, span, context),
                 AbstractSyntaxToken::EndControl(node_type) =>
-                    if let Some(range) = visitor.end_node_with_repeat_check(position, node_type, context) {
+                    if let Some(range) = visitor.end_node_with_repeat_check(position, node_type, span, context) {
                         for child_position in RangeInclusive::<usize>::from(&range) {
                             self.accept_node(child_position, visitor, context);
                         }
                     },
             },
-            Err(error) => visitor.token_error(error),
+            Err(error) => visitor.token_error(error, span),
         }
     }
-}
\ No newline at end of file
+}
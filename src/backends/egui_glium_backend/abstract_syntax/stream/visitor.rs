@@ -13,14 +13,14 @@ pub enum EndNodeAction {
 }
 
 pub trait AbstractSyntaxTokenStreamVisitor {
-    fn start_node_with_repeat_possibility(&mut self, position: usize, node_type: &AbstractSyntaxControlType, context: &mut DataContext) {
+    fn start_node_with_repeat_possibility(&mut self, position: usize, node_type: &AbstractSyntaxControlType, span: &SourceSpan, context: &mut DataContext) {
         self.push_last_node_position(position);
-        self.start_node(node_type, context);
+        self.start_node(node_type, span, context);
     }
 
-    fn end_node_with_repeat_check(&mut self, position: usize, node_type: &AbstractSyntaxControlType, context: &mut DataContext) -> Option<USizeRange> {
+    fn end_node_with_repeat_check(&mut self, position: usize, node_type: &AbstractSyntaxControlType, span: &SourceSpan, context: &mut DataContext) -> Option<USizeRange> {
         if let Some(last_node_position) = self.pop_last_node_position() {
-            if self.end_node(node_type, context) == EndNodeAction::Repeat {
+            if self.end_node(node_type, span, context) == EndNodeAction::Repeat {
                 return Some(USizeRange::new(last_node_position, position));
             }
         }
@@ -29,8 +29,8 @@ pub trait AbstractSyntaxTokenStreamVisitor {
 
     fn push_last_node_position(&mut self, position: usize);
     fn pop_last_node_position(&mut self) -> Option<usize>;
-    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, context: &mut DataContext);
-    fn property(&mut self, property: &AbstractSyntaxProperty, context: &mut DataContext);
-    fn end_node(&mut self, node_type: &AbstractSyntaxControlType, context: &mut DataContext) -> EndNodeAction;
-    fn token_error(&mut self, error: &AbstractSyntaxTokenError);
+    fn start_node(&mut self, node_type: &AbstractSyntaxControlType, span: &SourceSpan, context: &mut DataContext);
+    fn property(&mut self, property: &AbstractSyntaxProperty, span: &SourceSpan, context: &mut DataContext);
+    fn end_node(&mut self, node_type: &AbstractSyntaxControlType, span: &SourceSpan, context: &mut DataContext) -> EndNodeAction;
+    fn token_error(&mut self, error: &AbstractSyntaxTokenError, span: &SourceSpan);
 }
\ No newline at end of file
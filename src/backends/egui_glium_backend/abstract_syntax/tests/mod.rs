@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod schema;
\ No newline at end of file
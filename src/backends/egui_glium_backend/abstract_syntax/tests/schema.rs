@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+fn build_diagnostics(source: &str) -> (AbstractSyntax, Vec<(DiagnosticSeverity, String)>) {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", source);
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+    let diagnostics = ast.diagnostics()
+        .map(|diagnostic| (diagnostic.severity(), diagnostic.to_string()))
+        .collect();
+    (ast, diagnostics)
+}
+
+fn label_properties(ast: &AbstractSyntax) -> Vec<AbstractSyntaxPropertyType> {
+    let graph = ast.graph();
+    let label = graph.get_children(graph.get_root().unwrap())[0];
+    label.properties().iter().map(|property| property.property_type().clone()).collect()
+}
+
+#[test]
+fn misplaced_property_is_a_warning() {
+    let (ast, diagnostics) = build_diagnostics("<root><label text=\"a\" max-height=10 /></root>");
+
+    assert_eq!(vec!((
+        DiagnosticSeverity::Warning,
+        "memory/app.rux:1:34: property \"max-height\" is not supported by \"label\", expected one of [text, wrap, text-style, background-colour, colour, code, strong, weak, strike-through, underline, italics, raised]".to_string()
+    )), diagnostics);
+    assert_eq!(vec!(AbstractSyntaxPropertyType::Text), label_properties(&ast));
+}
+
+#[test]
+fn unknown_property_is_a_warning() {
+    let (ast, diagnostics) = build_diagnostics("<root><label text=\"a\" colr=\"red\" /></root>");
+
+    assert_eq!(vec!((DiagnosticSeverity::Warning, "memory/app.rux:1:29: unknown property \"colr\"".to_string())), diagnostics);
+    assert_eq!(vec!(AbstractSyntaxPropertyType::Text), label_properties(&ast));
+}
+
+#[test]
+fn misplaced_text_is_a_warning() {
+    let (_, diagnostics) = build_diagnostics("<root><separator>text</separator></root>");
+
+    assert_eq!(1, diagnostics.len());
+    assert!(diagnostics[0].1.contains("property \"text\" is not supported by \"separator\""));
+}
+
+#[test]
+fn valid_properties_have_no_diagnostics() {
+    let (ast, diagnostics) = build_diagnostics("<root><label text=\"a\" wrap=true colour=#ff0000 /></root>");
+
+    assert!(diagnostics.is_empty());
+    let properties = label_properties(&ast);
+    assert!(properties.contains(&AbstractSyntaxPropertyType::Wrap));
+    assert!(properties.contains(&AbstractSyntaxPropertyType::Colour));
+}
+
+#[test]
+fn schema_defaults_come_before_node_properties() {
+    let properties = vec!(create_ast_property(AbstractSyntaxPropertyType::Resizable, AbstractSyntaxPropertyValue::Bool(true)));
+    let with_defaults = control_schema(AbstractSyntaxControlType::TopPanel).with_defaults(&properties);
+    let property_types = with_defaults.iter().map(|property| property.property_type().clone()).collect::<Vec<AbstractSyntaxPropertyType>>();
+
+    assert_eq!(vec!(AbstractSyntaxPropertyType::Resizable, AbstractSyntaxPropertyType::HeightRange, AbstractSyntaxPropertyType::Resizable), property_types);
+    assert!(with_defaults[2].value().get_bool_value().unwrap());
+}
+
+#[test]
+fn value_types_accept_dynamic_values() {
+    let variable = AbstractSyntaxPropertyValue::VariablePath(VariablePath::parse("$selected".to_string()).unwrap());
+
+    assert!(PropertyValueType::Bool.accepts(&variable));
+    assert!(PropertyValueType::Bool.accepts(&AbstractSyntaxPropertyValue::Bool(false)));
+    assert!(!PropertyValueType::Bool.accepts(&AbstractSyntaxPropertyValue::String("yes".to_string())));
+    assert!(!PropertyValueType::Size.accepts(&AbstractSyntaxPropertyValue::Float(1.0)));
+}
+
+#[test]
+fn standard_controls_are_found_by_name() {
+    assert_eq!(Some(AbstractSyntaxControlType::Label), standard_control_type("label"));
+    assert_eq!(Some("scroll-area"), standard_control_name(AbstractSyntaxControlType::ScrollArea));
+    assert_eq!(None, standard_control_type("panel"));
+}
+
+#[test]
+fn misplaced_flag_is_a_warning() {
+    let (ast, diagnostics) = build_diagnostics("<root><label text=\"a\" resizable /></root>");
+
+    assert_eq!(vec!((
+        DiagnosticSeverity::Warning,
+        "memory/app.rux:1:23: property \"resizable\" is not supported by \"label\", expected one of [text, wrap, text-style, background-colour, colour, code, strong, weak, strike-through, underline, italics, raised]".to_string()
+    )), diagnostics);
+    assert_eq!(vec!(AbstractSyntaxPropertyType::Text), label_properties(&ast));
+}
+
+#[test]
+fn valued_flag_is_added_once() {
+    let (ast, diagnostics) = build_diagnostics("<root><label text=\"a\" wrap=true /></root>");
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(vec!(AbstractSyntaxPropertyType::Text, AbstractSyntaxPropertyType::Wrap), label_properties(&ast));
+}
\ No newline at end of file
@@ -5,14 +5,53 @@ pub enum AbstractSyntaxTokenError {
     SourceLocationError(SourceLocationError),
     SourceTokenError(SourceTokenError),
     CodeTokenError(CodeTokenError),
+    ArrayTokenError(ArrayTokenError),
+    UnexpectedCodeToken(String),
+    UnexpectedEndOfCode,
+    FunctionExpected,
     UnknownProperty(String),
     UnknownPropertyValue(String),
+    UnexpectedPropertyValue(String),
+    UnopenedControl(String),
     RangeValueParseError,
     ColourValueParseError,
     VariablePathParseError(String),
     TextStyleValueParseError(String)
 }
 
+impl AbstractSyntaxTokenError {
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            Self::SourceTokenError(error) => Some(error.span()),
+            Self::CodeTokenError(error) => Some(error.span()),
+            Self::ArrayTokenError(error) => Some(error.span()),
+            _ => None
+        }
+    }
+}
+
+impl std::fmt::Display for AbstractSyntaxTokenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourceLocationError(SourceLocationError::DoesNotExist) => write!(f, "source location does not exist"),
+            Self::SourceTokenError(error) => write!(f, "{}", error),
+            Self::CodeTokenError(error) => write!(f, "{}", error),
+            Self::ArrayTokenError(error) => write!(f, "{}", error),
+            Self::UnexpectedCodeToken(token) => write!(f, "unexpected code {:?}", token),
+            Self::UnexpectedEndOfCode => write!(f, "unexpected end of code"),
+            Self::FunctionExpected => write!(f, "expected a function"),
+            Self::UnknownProperty(property) => write!(f, "unknown property {:?}", property),
+            Self::UnknownPropertyValue(property) => write!(f, "unsupported value for property {:?}", property),
+            Self::UnexpectedPropertyValue(value) => write!(f, "unexpected value {}", value),
+            Self::UnopenedControl(control) => write!(f, "control {:?} is closed but never opened", control),
+            Self::RangeValueParseError => write!(f, "could not parse range value"),
+            Self::ColourValueParseError => write!(f, "could not parse colour value"),
+            Self::VariablePathParseError(path) => write!(f, "could not parse variable path {:?}", path),
+            Self::TextStyleValueParseError(style) => write!(f, "unknown text style {:?}", style)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AbstractSyntaxControlType {
     Unknown,
@@ -37,7 +76,8 @@ pub enum AbstractSyntaxControlType {
     SelectableLabel,
     Heading,
     Monospace,
-    Code
+    Code,
+    Custom
 }
 
 impl Default for AbstractSyntaxControlType {
@@ -99,8 +139,10 @@ pub enum AbstractSyntaxPropertyType {
     Colour, 
     BackgroundColour,
     OnSelect,
-    USizeRangeVariable,
-    FunctionVariable
+    ArrayVariable,
+    FunctionVariable,
+    ExpressionVariable,
+    Custom(String)
 }
 
 #[derive(Debug, Clone)]
@@ -8,19 +8,21 @@ impl From<Colour> for Color32 {
     }
 }
 
-impl From<&AbstractSyntaxPropertyValue> for egui::TextStyle {
-    fn from(from: &AbstractSyntaxPropertyValue) -> Self {
+impl TryFrom<&AbstractSyntaxPropertyValue> for egui::TextStyle {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &AbstractSyntaxPropertyValue) -> Result<Self, Self::Error> {
         match from {
             AbstractSyntaxPropertyValue::String(value) => 
                 match value.as_str() { 
-                    "small" => egui::TextStyle::Small,
-                    "body" => egui::TextStyle::Body,
-                    "button" => egui::TextStyle::Button,
-                    "heading" => egui::TextStyle::Heading,
-                    "monospace" => egui::TextStyle::Monospace,
-                    _=> panic!()
+                    "small" => Ok(egui::TextStyle::Small),
+                    "body" => Ok(egui::TextStyle::Body),
+                    "button" => Ok(egui::TextStyle::Button),
+                    "heading" => Ok(egui::TextStyle::Heading),
+                    "monospace" => Ok(egui::TextStyle::Monospace),
+                    _=> Err(AbstractSyntaxPropertyValueError::ValueNotExpected(from.clone()))
             },
-            _ => panic!()
+            _ => Err(AbstractSyntaxPropertyValueError::ValueNotExpected(from.clone()))
         }
     }
 }
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+use egui::{Align2, Color32};
+
+const DIAGNOSTICS_OVERLAY_ID: &str = "ruxy::diagnostics";
+const DIAGNOSTICS_OVERLAY_MARGIN: f32 = 8.0;
+const DIAGNOSTICS_OVERLAY_WIDTH: f32 = 480.0;
+const DIAGNOSTICS_OVERLAY_HEIGHT: f32 = 240.0;
+const DIAGNOSTIC_ERROR_COLOUR: Color32 = Color32::from_rgb(255, 110, 110);
+const DIAGNOSTIC_WARNING_COLOUR: Color32 = Color32::from_rgb(255, 200, 90);
+
+#[derive(Default)]
+pub struct DiagnosticsOverlay {
+    frame: Diagnostics,
+    runtime: Diagnostics,
+    shown: Diagnostics,
+    dismissed: bool
+}
+
+impl DiagnosticsOverlay {
+    pub fn add_frame_diagnostic(&mut self, diagnostic: Diagnostic) {
+        self.frame.push(diagnostic);
+    }
+
+    pub fn add_runtime_diagnostic(&mut self, diagnostic: Diagnostic) {
+        self.runtime.push(diagnostic);
+    }
+
+    pub fn end_frame(&mut self) {
+        let mut diagnostics = std::mem::take(&mut self.frame);
+        diagnostics.extend(self.runtime.clone());
+        let mut arrived = false;
+        for diagnostic in diagnostics.iter().filter(|diagnostic| !self.shown.contains(diagnostic)) {
+            error!("{}", diagnostic);
+            arrived = true;
+        }
+        if arrived {
+            self.dismissed = false;
+        }
+        self.shown = diagnostics;
+    }
+
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+        self.runtime = Diagnostics::default();
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.dismissed && !self.shown.is_empty()
+    }
+
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.shown
+    }
+}
+
+impl AbstractSyntaxGraphRenderer {
+    pub fn render_diagnostics_overlay(&self) {
+        let mut overlay = self.diagnostics.borrow_mut();
+        if !overlay.is_open() {
+            return;
+        }
+        let diagnostics = overlay.diagnostics();
+        let mut open = true;
+        egui::Window::new(format!("Diagnostics ({})", diagnostics.len()))
+            .id(egui::Id::new(DIAGNOSTICS_OVERLAY_ID))
+            .anchor(Align2::RIGHT_BOTTOM, [-DIAGNOSTICS_OVERLAY_MARGIN, -DIAGNOSTICS_OVERLAY_MARGIN])
+            .default_width(DIAGNOSTICS_OVERLAY_WIDTH)
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(self.egui.ctx(), | ui | {
+                egui::ScrollArea::from_max_height(DIAGNOSTICS_OVERLAY_HEIGHT).show(ui, | ui | {
+                    for diagnostic in diagnostics.iter() {
+                        render_diagnostic(ui, diagnostic);
+                    }
+                });
+            });
+        if !open {
+            overlay.dismiss();
+        }
+    }
+}
+
+fn render_diagnostic(ui: &mut egui::Ui, diagnostic: &Diagnostic) {
+    if let Some(span) = diagnostic.span() {
+        let location = span.to_string();
+        if ui.selectable_label(false, &location).on_hover_text("Click to copy location").clicked() {
+            ui.output().copied_text = location;
+        }
+    }
+    ui.colored_label(diagnostic_colour(diagnostic.severity()), diagnostic.message());
+    ui.separator();
+}
+
+fn diagnostic_colour(severity: DiagnosticSeverity) -> Color32 {
+    match severity {
+        DiagnosticSeverity::Error => DIAGNOSTIC_ERROR_COLOUR,
+        DiagnosticSeverity::Warning => DIAGNOSTIC_WARNING_COLOUR
+    }
+}
\ No newline at end of file
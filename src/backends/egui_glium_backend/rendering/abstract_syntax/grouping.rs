@@ -8,7 +8,7 @@ impl AbstractSyntaxGraphRenderer {
     pub fn render_scroll_area(&self, ui: &mut egui::Ui, props: ScrollAreaProperties, add_contents: impl FnOnce(&mut egui::Ui) -> ()) {
         let mut scroll_area = match props.size {
             None => render_auto_sized_scroll_area(),
-            Some(height) => render_max_height_scroll_area(height),
+            Some(height) => render_max_height_scroll_area(height.resolve(ui.available_height(), self.em_size())),
         };
 
         scroll_area = scroll_area
@@ -43,7 +43,7 @@ fn render_max_height_scroll_area(height: f32) -> egui::ScrollArea {
 
 pub struct ScrollAreaProperties {
     pub id: String,
-    pub size: Option<f32>,
+    pub size: Option<Size>,
     pub scroll_offset: Option<f32>,
     pub always_show_scroll: bool,
     pub enable_scrolling: bool,
@@ -61,20 +61,26 @@ impl Default for ScrollAreaProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for ScrollAreaProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for ScrollAreaProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
+        let mut auto_sized = false;
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::VerticallySized => to.size = Some(property.value().get_float_value().unwrap()),
-                AbstractSyntaxPropertyType::AutoSized => to.size = None,
-                AbstractSyntaxPropertyType::AlwaysShowScroll => to.always_show_scroll = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::ScrollOffset => to.scroll_offset = Some(property.value().get_float_value().unwrap()),
-                AbstractSyntaxPropertyType::EnableScrolling => to.enable_scrolling = property.value().get_bool_value().unwrap(),
+                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value()?,
+                AbstractSyntaxPropertyType::VerticallySized => to.size = Some(property.value().get_size_value()?),
+                AbstractSyntaxPropertyType::AutoSized => auto_sized = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::AlwaysShowScroll => to.always_show_scroll = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::ScrollOffset => to.scroll_offset = Some(property.value().get_float_value()?),
+                AbstractSyntaxPropertyType::EnableScrolling => to.enable_scrolling = property.value().get_bool_value()?,
                 _ => {}
             }
         }
-        to
+        if auto_sized {
+            to.size = None;
+        }
+        Ok(to)
     }
 }
\ No newline at end of file
@@ -51,7 +51,9 @@ impl AbstractSyntaxGraphRenderer {
     pub fn render_selectable_label(&self, ui: &mut egui::Ui, context: &mut DataContext, props: SelectableLabelProperties) {
         let response = ui.selectable_label(props.selected, props.text);
         if response.clicked() {
-            context.run_action_function(&props.on_selected).unwrap();
+            if let Err(error) = context.run_action_function(&props.on_selected) {
+                self.diagnostics.borrow_mut().add_runtime_diagnostic(Diagnostic::error(error.to_string()));
+            }
         }
     }
 
@@ -102,27 +104,29 @@ impl Default for LabelProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for LabelProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for LabelProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::Wrap => to.wrap = Some(property.value().get_bool_value().unwrap()),
-                AbstractSyntaxPropertyType::TextStyle => to.text_style = Some(property.value().into()),
-                AbstractSyntaxPropertyType::BackgroundColour => to.background_color = property.value().get_colour_value().unwrap().into(),
-                AbstractSyntaxPropertyType::Colour => to.text_color = Some(property.value().get_colour_value().unwrap().into()),
-                AbstractSyntaxPropertyType::Code => to.code = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Strong => to.strong = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Weak => to.weak = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Strikethrough => to.strikethrough = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Underline => to.underline = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Italics => to.italics = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::Raised => to.raised = property.value().get_bool_value().unwrap(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
+                AbstractSyntaxPropertyType::Wrap => to.wrap = Some(property.value().get_bool_value()?),
+                AbstractSyntaxPropertyType::TextStyle => to.text_style = Some(property.value().try_into()?),
+                AbstractSyntaxPropertyType::BackgroundColour => to.background_color = property.value().get_colour_value()?.into(),
+                AbstractSyntaxPropertyType::Colour => to.text_color = Some(property.value().get_colour_value()?.into()),
+                AbstractSyntaxPropertyType::Code => to.code = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Strong => to.strong = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Weak => to.weak = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Strikethrough => to.strikethrough = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Underline => to.underline = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Italics => to.italics = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::Raised => to.raised = property.value().get_bool_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 pub struct ColouredLabelProperties {
@@ -139,17 +143,19 @@ impl Default for ColouredLabelProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for ColouredLabelProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for ColouredLabelProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::Colour => to.colour = property.value().get_colour_value().unwrap().into(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
+                AbstractSyntaxPropertyType::Colour => to.colour = property.value().get_colour_value()?.into(),
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 
@@ -160,18 +166,20 @@ pub struct SelectableLabelProperties {
     pub on_selected: Function
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for SelectableLabelProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for SelectableLabelProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::Selected => to.selected = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::OnSelect => to.on_selected = property.value().get_function_value().unwrap(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
+                AbstractSyntaxPropertyType::Selected => to.selected = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::OnSelect => to.on_selected = property.value().get_function_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 
@@ -187,16 +195,18 @@ impl Default for MonospaceProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for MonospaceProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for MonospaceProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 
@@ -212,16 +222,18 @@ impl Default for CodeProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for CodeProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for CodeProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 
@@ -237,15 +249,17 @@ impl Default for HeadingProperties {
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for HeadingProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for HeadingProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Text => to.text = property.value().get_string_value().unwrap(),
+                AbstractSyntaxPropertyType::Text => to.text = property.value().get_display_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
@@ -1,27 +1,33 @@
 mod panels;
 mod labels;
 mod grouping;
+mod diagnostics;
 
 pub use panels::*;
 pub use labels::*;
 pub use grouping::*;
+pub use diagnostics::*;
 
 use crate::prelude::*;
 use egui_glium::*;
+use std::cell::RefCell;
 
+const DEFAULT_EM_SIZE: f32 = 14.0;
 
 pub fn create_graph_renderer(display: &Display) -> AbstractSyntaxGraphRenderer {
     AbstractSyntaxGraphRenderer::new(display)
 }
 
 pub struct AbstractSyntaxGraphRenderer {
-    egui: EguiGlium
+    egui: EguiGlium,
+    diagnostics: RefCell<DiagnosticsOverlay>
 }
 
 impl AbstractSyntaxGraphRenderer {
     pub fn new(display: &Display) -> Self {
         Self {
-            egui: EguiGlium::new(display)
+            egui: EguiGlium::new(display),
+            diagnostics: RefCell::new(DiagnosticsOverlay::default())
         }
     }
 
@@ -30,16 +36,13 @@ impl AbstractSyntaxGraphRenderer {
     }
    
     pub fn render(&mut self, context: &mut DataContext, graph: &AbstractSyntaxGraph, display: &Display, frame: &mut Frame) -> bool {
-        if let Some(root) = graph.get_root() {
-            return self.render_root(context, graph, root, display, frame);
-        }
-        false     
-    }
-
-    pub fn render_root(&mut self, context: &mut DataContext, graph: &AbstractSyntaxGraph, root: &AbstractSyntaxGraphNode, display: &Display, frame: &mut Frame) -> bool {
         self.begin_frame(display);
         self.set_visuals();
-        self.render_top_levels(context, graph, graph.get_children(root));
+        if let Some(root) = graph.get_root() {
+            self.render_top_levels(context, graph, graph.get_children(root));
+        }
+        self.diagnostics.borrow_mut().end_frame();
+        self.render_diagnostics_overlay();
         self.end_frame_and_paint(display, frame)
     }
 
@@ -54,13 +57,13 @@ impl AbstractSyntaxGraphRenderer {
             AbstractSyntaxControlType::CentralPanel =>
                 self.render_central_panel(| ui | self.render_children(ui, context, graph, node)),
             AbstractSyntaxControlType::TopPanel =>
-                self.render_top_panel(node.properties().into(), | ui | self.render_children(ui, context, graph, node)),
+                self.render_with_properties(node, | props | self.render_top_panel(props, | ui | self.render_children(ui, context, graph, node))),
             AbstractSyntaxControlType::BottomPanel =>
-                self.render_bottom_panel(node.properties().into(), | ui | self.render_children(ui, context, graph, node)),
+                self.render_with_properties(node, | props | self.render_bottom_panel(props, | ui | self.render_children(ui, context, graph, node))),
             AbstractSyntaxControlType::LeftSidebar =>
-                self.render_left_side_panel(node.properties().into(), | ui | self.render_children(ui, context, graph, node)),
+                self.render_with_properties(node, | props | self.render_left_side_panel(props, | ui | self.render_children(ui, context, graph, node))),
             AbstractSyntaxControlType::RightSidebar =>
-                self.render_right_side_panel(node.properties().into(), | ui | self.render_children(ui, context, graph, node)),
+                self.render_with_properties(node, | props | self.render_right_side_panel(props, | ui | self.render_children(ui, context, graph, node))),
             _ => {}
         }
     }
@@ -76,7 +79,7 @@ impl AbstractSyntaxGraphRenderer {
             AbstractSyntaxControlType::Container => 
                 self.render_children(ui, context, graph, child),
             AbstractSyntaxControlType::ScrollArea => 
-                self.render_scroll_area(ui, child.properties().into(), | ui | self.render_children(ui, context, graph, child)),
+                self.render_with_properties(child, | props | self.render_scroll_area(ui, props, | ui | self.render_children(ui, context, graph, child))),
             AbstractSyntaxControlType::Separator => 
                 self.render_separator(ui),
             AbstractSyntaxControlType::Horizontal => 
@@ -84,21 +87,57 @@ impl AbstractSyntaxGraphRenderer {
             AbstractSyntaxControlType::Vertical => 
                 self.render_vertical(ui, | ui | self.render_children(ui, context, graph, child)),
             AbstractSyntaxControlType::Label => 
-                self.render_label(ui, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_label(ui, props)),
             AbstractSyntaxControlType::ColouredLabel => 
-                self.render_coloured_label(ui, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_coloured_label(ui, props)),
             AbstractSyntaxControlType::SelectableLabel => 
-                self.render_selectable_label(ui, context, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_selectable_label(ui, context, props)),
             AbstractSyntaxControlType::Heading => 
-                self.render_heading(ui, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_heading(ui, props)),
             AbstractSyntaxControlType::Monospace => 
-                self.render_monospace(ui, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_monospace(ui, props)),
             AbstractSyntaxControlType::Code => 
-                self.render_code(ui, child.properties().into()),
+                self.render_with_properties(child, | props | self.render_code(ui, props)),
+            AbstractSyntaxControlType::Custom => 
+                self.render_custom_control(ui, context, child),
             _ => {}
         }
     }
 
+    fn render_custom_control(&self, ui: &mut egui::Ui, context: &DataContext, node: &AbstractSyntaxGraphNode) {
+        let name = node.properties().iter()
+            .find(|property| property.property_type() == &AbstractSyntaxPropertyType::Name)
+            .and_then(|property| property.value().get_string_value().ok())
+            .unwrap_or_default();
+        match context.controls().get_control(&name) {
+            Some(control) => control.render(&CustomControlProperties::new(control.schema().with_defaults(node.properties())), ui),
+            None => self.add_diagnostic(Diagnostic::error(format!("custom control {:?} is not registered", name)))
+        }
+    }
+
+    fn render_with_properties<T>(&self, node: &AbstractSyntaxGraphNode, render: impl FnOnce(T))
+    where for<'a> T: TryFrom<&'a Vec<AbstractSyntaxProperty>, Error = AbstractSyntaxPropertyValueError> {
+        match T::try_from(&control_schema(node.node_type()).with_defaults(node.properties())) {
+            Ok(props) => render(props),
+            Err(error) => self.add_diagnostic(Diagnostic::error(format!("{:?}: {}", node.node_type(), error)))
+        }
+    }
+
+    pub fn add_diagnostic(&self, diagnostic: Diagnostic) {
+        self.diagnostics.borrow_mut().add_frame_diagnostic(diagnostic);
+    }
+
+    pub fn screen_size(&self) -> egui::Vec2 {
+        self.egui.ctx().input().screen_rect().size()
+    }
+
+    pub fn em_size(&self) -> f32 {
+        match self.egui.ctx().fonts().definitions().family_and_size.get(&egui::TextStyle::Body) {
+            Some((_, size)) => *size,
+            None => DEFAULT_EM_SIZE
+        }
+    }
+
     fn set_visuals(&mut self) {
         let mut visuals = egui::Visuals::dark();
         visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgba_premultiplied(0, 0, 0, 220);
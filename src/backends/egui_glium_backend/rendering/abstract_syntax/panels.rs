@@ -8,18 +8,25 @@ impl AbstractSyntaxGraphRenderer {
     }
 
     pub fn render_left_side_panel(&self, props: SidePanelProperties, contents: impl FnOnce(&mut egui::Ui) -> ()) {
-        egui::SidePanel::left(props.id)
-            .resizable(props.resizable)
-            .default_width(props.default_width)
-            .width_range(props.width_range)
-            .show(self.egui.ctx(), contents);
+        self.render_side_panel(&props, egui::SidePanel::left(&props.id), contents);
     }
 
     pub fn render_right_side_panel(&self, props: SidePanelProperties, contents: impl FnOnce(&mut egui::Ui) -> ()) {
-        egui::SidePanel::right(props.id)
+        self.render_side_panel(&props, egui::SidePanel::right(&props.id), contents);
+    }
+
+    fn render_side_panel(
+        &self,
+        props: &SidePanelProperties,
+        panel: egui::SidePanel,
+        contents: impl FnOnce(&mut egui::Ui)
+    ) {
+        let available_width = self.screen_size().x;
+        let em = self.em_size();
+        panel
             .resizable(props.resizable)
-            .default_width(props.default_width)
-            .width_range(props.width_range)
+            .default_width(props.default_width.resolve(available_width, em))
+            .width_range(props.width_range.resolve(available_width, em))
             .show(self.egui.ctx(), contents);
     }
 
@@ -37,11 +44,13 @@ impl AbstractSyntaxGraphRenderer {
         mut panel: egui::TopBottomPanel,
         contents: impl FnOnce(&mut egui::Ui)
     ) {
+        let available_height = self.screen_size().y;
+        let em = self.em_size();
         if let Some(default_height) = props.default_height {
-            panel = panel.default_height(default_height)
+            panel = panel.default_height(default_height.resolve(available_height, em))
         }
         panel
-            .height_range(props.height_range.clone())
+            .height_range(props.height_range.resolve(available_height, em))
             .show(self.egui.ctx(), contents);
     }
 }
@@ -49,8 +58,8 @@ impl AbstractSyntaxGraphRenderer {
 pub struct TopBottomPanelProperties {
     pub id: String,
     pub resizable: bool,
-    pub default_height: Option<f32>,
-    pub height_range: RangeInclusive<f32>,
+    pub default_height: Option<Size>,
+    pub height_range: SizeRange,
 }
 
 impl Default for TopBottomPanelProperties {
@@ -59,32 +68,34 @@ impl Default for TopBottomPanelProperties {
             id: "".to_string(),
             resizable: false,
             default_height: None,
-            height_range: 96.0..=f32::INFINITY
+            height_range: SizeRange::new(Size::points(96.0), Size::points(f32::INFINITY))
         }
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for TopBottomPanelProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for TopBottomPanelProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::Resizable => to.resizable = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::DefaultHeight => to.default_height = Some(property.value().get_float_value().unwrap()),
-                AbstractSyntaxPropertyType::HeightRange => to.height_range = property.value().get_float_range_value().unwrap(),
+                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value()?,
+                AbstractSyntaxPropertyType::Resizable => to.resizable = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::DefaultHeight => to.default_height = Some(property.value().get_size_value()?),
+                AbstractSyntaxPropertyType::HeightRange => to.height_range = property.value().get_size_range_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
 
 pub struct SidePanelProperties {
     pub id: String,
     pub resizable: bool,
-    pub default_width: f32,
-    pub width_range: RangeInclusive<f32>,
+    pub default_width: Size,
+    pub width_range: SizeRange,
 }
 
 impl Default for SidePanelProperties {
@@ -92,24 +103,26 @@ impl Default for SidePanelProperties {
         Self { 
             id: "".to_string(),
             resizable: false,
-            default_width: 200.0,
-            width_range: 96.0..=f32::INFINITY
+            default_width: Size::points(200.0),
+            width_range: SizeRange::new(Size::points(96.0), Size::points(f32::INFINITY))
         }
     }
 }
 
-impl From<&Vec<AbstractSyntaxProperty>> for SidePanelProperties {
-    fn from(from: &Vec<AbstractSyntaxProperty>) -> Self {
+impl TryFrom<&Vec<AbstractSyntaxProperty>> for SidePanelProperties {
+    type Error = AbstractSyntaxPropertyValueError;
+
+    fn try_from(from: &Vec<AbstractSyntaxProperty>) -> Result<Self, Self::Error> {
         let mut to = Self::default();
         for property in from {
             match property.property_type() {
-                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value().unwrap(),
-                AbstractSyntaxPropertyType::Resizable => to.resizable = property.value().get_bool_value().unwrap(),
-                AbstractSyntaxPropertyType::DefaultWidth => to.default_width = property.value().get_float_value().unwrap(),
-                AbstractSyntaxPropertyType::WidthRange => to.width_range = property.value().get_float_range_value().unwrap(),
+                AbstractSyntaxPropertyType::Id => to.id = property.value().get_string_value()?,
+                AbstractSyntaxPropertyType::Resizable => to.resizable = property.value().get_bool_value()?,
+                AbstractSyntaxPropertyType::DefaultWidth => to.default_width = property.value().get_size_value()?,
+                AbstractSyntaxPropertyType::WidthRange => to.width_range = property.value().get_size_range_value()?,
                 _ => {}
             }
         }
-        to
+        Ok(to)
     }
 }
@@ -53,6 +53,17 @@ pub fn get_items(state: &mut State) -> Vec<SelectedItem> {
         .collect()
 }
 
+pub fn create_gauge_control() -> CustomControl {
+    let schema = ControlSchema::new(AbstractSyntaxControlType::Custom)
+        .with_custom_property("value", PropertyValueType::Float)
+        .with_custom_default("text", PropertyValueType::Text, AbstractSyntaxPropertyValue::String("".to_string()));
+    CustomControl::new("gauge", schema, |properties, ui| {
+        let value = properties.get("value").and_then(|value| value.get_float_value().ok()).unwrap_or_default();
+        let text = properties.get("text").and_then(|text| text.get_display_value().ok()).unwrap_or_default();
+        ui.add(egui::ProgressBar::new(value).text(text));
+    })
+}
+
 // #[data_item]
 pub struct SelectedItem {
     pub text: String,
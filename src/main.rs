@@ -8,14 +8,13 @@ mod abstract_syntax;
 mod examples;
 
 mod prelude {
-    pub use log::{debug,info}; 
+    pub use log::{debug,info,error}; 
     pub use std::error::Error; 
     pub use std::fmt::{ Formatter }; 
     pub use std::ops::*;
     pub use std::collections::*;
     pub use std::marker::PhantomData;
-    pub use std::iter::Enumerate;
-    pub use std::str::Chars;
+    pub use std::str::CharIndices;
     pub use core::fmt::Debug;
     pub use core::time::Duration;
     pub use crate::application::*;
@@ -30,10 +29,29 @@ mod prelude {
 use crate::prelude::*;
 
 fn main() {
-    Application::default()
+    let file_path_base = match cfg!(debug_assertions) {
+        true => FilePathBase::WorkingDirectory,
+        false => FilePathBase::Executable
+    };
+    let application = Application::default()
         .use_logging()
         .with_file_path("examples/assets/first")
+        .with_file_path_base(file_path_base)
+        .with_file_path_environment_override("RUXY_FOLDER");
+    if std::env::args().any(|argument| argument == "--format") {
+        if let Err(errors) = application.format_sources() {
+            for error in errors {
+                eprintln!("{}", error);
+            }
+            std::process::exit(1);
+        }
+        return;
+    }
+    application
+        .with_embedded_sources(compiled_embedded_sources())
+        .with_root(DEFAULT_ROOT_NAME)
         .with_file_monitor_poll(Duration::from_secs(1))
+        .with_control(examples::first::create_gauge_control())
         .with_context(|ctx| examples::first::register(ctx))
         .build()
         .expect("Build error")
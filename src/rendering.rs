@@ -31,6 +31,9 @@ impl Renderer {
     }
 
     fn render_gui(&mut self, context: &mut DataContext, ast: &mut AbstractSyntax, target: &mut Frame) {
+        for diagnostic in ast.diagnostics() {
+            self.graph_renderer.add_diagnostic(diagnostic.clone());
+        }
         if self.graph_renderer.render(context, ast.graph(), &self.screen_renderer.display, target) {
             self.screen_renderer.display.gl_window().window().request_redraw();
         }
@@ -0,0 +1,4 @@
+mod sources;
+mod tests;
+
+pub use sources::*;
\ No newline at end of file
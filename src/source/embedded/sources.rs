@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+pub const EMBEDDED_SOURCE_ROOT: &str = "embedded";
+
+static COMPILED_SOURCES: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_sources.rs"));
+
+pub fn compiled_embedded_sources() -> Option<EmbeddedSources> {
+    match cfg!(feature = "embedded-sources") {
+        true => Some(EmbeddedSources::compiled()),
+        false => None
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct EmbeddedSources {
+    sources: &'static [(&'static str, &'static str)]
+}
+
+impl EmbeddedSources {
+    pub fn new(sources: &'static [(&'static str, &'static str)]) -> Self {
+        Self {
+            sources
+        }
+    }
+
+    pub fn compiled() -> Self {
+        Self::new(COMPILED_SOURCES)
+    }
+
+    fn to_location(relative_path: &str) -> SourceLocation {
+        PathBuf::from(EMBEDDED_SOURCE_ROOT).join(relative_path).to_normalised_source_location()
+    }
+}
+
+impl SourceReader for EmbeddedSources {
+    fn read_source_at_location(&self, location: &SourceLocation) -> Result<String, SourceReaderError> {
+        self.sources.iter()
+            .find(|(relative_path, _)| &Self::to_location(relative_path) == location)
+            .map(|(_, source)| source.to_string())
+            .ok_or(SourceReaderError::ErrorReadingSource)
+    }
+}
+
+impl SourceLocationWalker<Vec<SourceLocation>> for EmbeddedSources {
+    fn walk(&self, _paths: &FilePaths) -> Result<Vec<SourceLocation>, SourceLocationWalkerError> {
+        debug!("walking {} embedded sources", self.sources.len());
+        Ok(self.sources.iter().map(|(relative_path, _)| Self::to_location(relative_path)).collect())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod sources;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+static SOURCES: &[(&str, &str)] = &[
+    ("app.rux", "<root><control name=\"panel\" path=\"controls/panel.rux\" /></root>"),
+    ("controls/panel.rux", "<label>panel</label>")
+];
+
+#[test]
+fn embedded_sources_walk_every_manifest_entry_under_embedded_root() {
+    let locations = EmbeddedSources::new(SOURCES).walk(&FilePaths::default()).unwrap();
+    assert_eq!(
+        vec!(
+            PathBuf::from("embedded/app.rux").to_normalised_source_location(),
+            PathBuf::from("embedded/controls/panel.rux").to_normalised_source_location()
+        ),
+        locations
+    );
+}
+
+#[test]
+fn embedded_sources_read_source_at_walked_location() {
+    let sources = EmbeddedSources::new(SOURCES);
+    let locations = sources.walk(&FilePaths::default()).unwrap();
+    assert_eq!("<label>panel</label>", sources.read_source_at_location(&locations[1]).unwrap());
+    assert!(sources.read_source_at_location(&SourceLocation::from("missing.rux")).is_err());
+}
+
+#[test]
+fn embedded_relative_location_resolves_without_file_system() {
+    let sources = EmbeddedSources::new(SOURCES);
+    let locations = sources.walk(&FilePaths::default()).unwrap();
+    assert_eq!(Ok(locations[1].clone()), locations[0].to_relative_location("./controls/../controls/panel.rux"));
+}
+
+#[test]
+fn embedded_source_files_parse_every_source_once() {
+    let mut source_files = SourceFiles::embedded(FilePaths::default(), EmbeddedSources::new(SOURCES));
+    let changes = source_files.process().unwrap();
+    assert_eq!(2, changes.iter().count());
+    for location in changes.iter() {
+        assert!(source_files.lookup(location).is_some());
+    }
+    assert!(source_files.process().unwrap().is_empty());
+}
\ No newline at end of file
@@ -15,7 +15,9 @@ impl From<SourceLocation> for SourceChanges {
 
 impl SourceChanges {
     pub fn push(&mut self, change: SourceLocation) {
-        self.0.push(change);
+        if !self.0.contains(&change) {
+            self.0.push(change);
+        }
     }
 
     pub fn iter(&self) -> std::slice::Iter<'_, SourceLocation> {
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use std::fs;
+
+#[derive(Debug)]
+pub enum SourceFileFormatError {
+    Walking(SourceLocationWalkerError),
+    Reading(SourceLocation, SourceReaderError),
+    Writing(SourceLocation, std::io::Error),
+    Formatting(SourceLocation, Box<SourceFormatError>)
+}
+
+impl std::fmt::Display for SourceFileFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceFileFormatError::Walking(error) => write!(f, "{}", error),
+            SourceFileFormatError::Reading(location, error) => write!(f, "{}: {}", location, error),
+            SourceFileFormatError::Writing(location, error) => write!(f, "{}: {}", location, error),
+            SourceFileFormatError::Formatting(location, error) => write!(f, "{}:{}", location, error)
+        }
+    }
+}
+
+impl From<SourceLocationWalkerError> for SourceFileFormatError {
+    fn from(error: SourceLocationWalkerError) -> SourceFileFormatError {
+        SourceFileFormatError::Walking(error)
+    }
+}
+
+pub fn format_source_files(file_paths: &FilePaths, formatter: &SourceFormatter) -> Result<Vec<SourceLocation>, Vec<SourceFileFormatError>> {
+    let source_reader = create_source_file_reader();
+    let locations = create_file_system_source_location_walker().walk(file_paths)
+        .map_err(|error| vec!(error.into()))?;
+    let mut formatted_sources = vec!();
+    let mut errors = vec!();
+
+    for location in locations {
+        let source_text = match source_reader.read_source_at_location(&location) {
+            Ok(source_text) => source_text,
+            Err(error) => {
+                errors.push(SourceFileFormatError::Reading(location, error));
+                continue;
+            }
+        };
+        match formatter.format_string(&source_text) {
+            Ok(formatted_text) if formatted_text != source_text => formatted_sources.push((location, formatted_text)),
+            Ok(_) => {},
+            Err(error) => errors.push(SourceFileFormatError::Formatting(location, Box::new(error)))
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut changed_locations = vec!();
+    for (location, formatted_text) in formatted_sources {
+        debug!("formatting {:?}", location);
+        fs::write((&location).to_path_buf(), formatted_text).map_err(|error| vec!(SourceFileFormatError::Writing(location.clone(), error)))?;
+        changed_locations.push(location);
+    }
+    Ok(changed_locations)
+}
\ No newline at end of file
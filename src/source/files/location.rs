@@ -5,6 +5,7 @@ use crate::prelude::*;
 pub trait ToSourceLocationConversion {
     fn to_canonicalised_source_location(&self) -> Result<SourceLocation, SourceLocationError>;
     fn to_source_location(&self) -> SourceLocation;
+    fn to_normalised_source_location(&self) -> SourceLocation;
 }
 
 pub trait ToPathBufConversion {
@@ -30,22 +31,30 @@ fn remove_canonicalization_prefix<P: AsRef<std::path::Path>>(path: P) -> PathBuf
 
 impl ToSourceLocationConversion for PathBuf {    
     fn to_canonicalised_source_location(&self) -> Result<SourceLocation, SourceLocationError> {
-        if let Ok(path) = self.canonicalize() {
-            Ok(SourceLocation { 
-                location: match remove_canonicalization_prefix(path).to_str() {
-                    Some(path) => Some(path.to_owned().replace("/", "\\")),
-                    None => None
-                }
-            })
-        } else {
-            Err(SourceLocationError::DoesNotExist)
-        }
+        let path = match (self.canonicalize(), self.parent(), self.file_name()) {
+            (Ok(path), _, _) => path,
+            (Err(_), Some(parent), Some(file_name)) => parent.canonicalize().map_err(|_| SourceLocationError::DoesNotExist)?.join(file_name),
+            _ => return Err(SourceLocationError::DoesNotExist)
+        };
+        Ok(SourceLocation::new(remove_canonicalization_prefix(path)))
     }
 
     fn to_source_location(&self) -> SourceLocation {
-        SourceLocation { 
-            location: Some(self.to_str().unwrap().to_owned())
+        SourceLocation::new(self.clone())
+    }
+
+    fn to_normalised_source_location(&self) -> SourceLocation {
+        let mut path = PathBuf::new();
+        for component in self.components() {
+            match component {
+                Component::CurDir => {},
+                Component::ParentDir => {
+                    path.pop();
+                },
+                other => path.push(other)
+            }
         }
+        path.to_source_location()
     }
 }
 
@@ -58,6 +67,10 @@ impl ToSourceLocationConversion for &str {
     fn to_source_location(&self) -> SourceLocation {
         PathBuf::from(self).to_source_location()
     }
+
+    fn to_normalised_source_location(&self) -> SourceLocation {
+        PathBuf::from(self).to_normalised_source_location()
+    }
 }
 
 impl ToSourceLocationConversion for String {    
@@ -68,13 +81,17 @@ impl ToSourceLocationConversion for String {
     fn to_source_location(&self) -> SourceLocation {
         PathBuf::from(self).to_source_location()
     }
+
+    fn to_normalised_source_location(&self) -> SourceLocation {
+        PathBuf::from(self).to_normalised_source_location()
+    }
 }
 
 impl ToPathBufConversion for &SourceLocation {    
     fn to_path_buf(&self) -> std::path::PathBuf {
-        if let Some(location) = &self.location {
-            return std::path::PathBuf::from(location)
+        match self.path() {
+            Some(path) => path.to_path_buf(),
+            None => std::path::PathBuf::new()
         }
-        std::path::PathBuf::new()
     }
 }
\ No newline at end of file
@@ -2,13 +2,16 @@ mod monitoring;
 mod location;
 mod reading;
 mod changes;
+mod formatting;
+mod tests;
 
 pub use monitoring::*;
 pub use location::*;
 pub use reading::*;
 pub use changes::*;
+pub use formatting::*;
 
-pub use std::path::PathBuf;
+pub use std::path::{Path, PathBuf};
 
 use crate::prelude::*;
 
@@ -22,12 +25,26 @@ pub enum FileMonitorError {
 pub enum FileMonitorWatchError {
     NoLongerMonitoring,
     NoFileChanges,
+    WatchError(String, Option<PathBuf>),
     SourceLocationError(SourceLocationError)
 }
 
+impl std::fmt::Display for FileMonitorWatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoLongerMonitoring => write!(f, "file monitoring stopped"),
+            Self::NoFileChanges => write!(f, "no file changes"),
+            Self::WatchError(message, Some(path)) => write!(f, "{}: {}", path.display(), message),
+            Self::WatchError(message, None) => write!(f, "{}", message),
+            Self::SourceLocationError(SourceLocationError::DoesNotExist) => write!(f, "source location does not exist")
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum FilePathError {
-    ManifestDirectoryEnvironmentVariableNotSet
+    WorkingDirectoryNotFound,
+    ExecutableDirectoryNotFound
 }
 
 impl From<FilePathError> for FileMonitorError {
@@ -42,33 +59,60 @@ impl From<SourceLocationError> for FileMonitorWatchError {
     }
 }
 
+pub type BoxedSourceReader = Box<dyn SourceReader>;
+pub type BoxedSourceLocationWalker = Box<dyn SourceLocationWalker<Vec<SourceLocation>>>;
+pub type BoxedFileMonitor = Box<dyn FileMonitor>;
+
 pub struct SourceFiles {
     file_paths: FilePaths,
-    source_reader: FileSourceReader,
+    source_reader: BoxedSourceReader,
+    source_location_walker: BoxedSourceLocationWalker,
+    monitor: BoxedFileMonitor,
     source_tokens_lookup: SourceLookup,
-    monitor: FileSystemFileMonitor,
+    diagnostics: Diagnostics,
     initially_parsed: bool
 }
 
 impl SourceFiles {
-    pub fn new(relative_rux_folder_path: &'static str, file_monitor_poll: Duration) -> Result<Self, RuxError> {
-        let file_paths = create_file_paths(relative_rux_folder_path);
-        
-        let source_files = SourceFiles { 
+    pub fn new(file_paths: FilePaths, file_monitor_poll: Duration) -> Result<Self, RuxError> {
+        let monitor = monitor_files(file_paths.clone(), file_monitor_poll)?;
+        Ok(Self::from_backends(
+            file_paths,
+            Box::new(create_source_file_reader()),
+            Box::new(create_file_system_source_location_walker()),
+            Box::new(monitor)
+        ))
+    }
+
+    pub fn embedded(file_paths: FilePaths, sources: EmbeddedSources) -> Self {
+        Self::from_backends(file_paths, Box::new(sources), Box::new(sources), Box::new(StaticFileMonitor))
+    }
+
+    pub fn from_backends(
+        file_paths: FilePaths,
+        source_reader: BoxedSourceReader,
+        source_location_walker: BoxedSourceLocationWalker,
+        monitor: BoxedFileMonitor
+    ) -> Self {
+        SourceFiles {
             file_paths,
-            source_reader: create_source_file_reader(),
+            source_reader,
+            source_location_walker,
+            monitor,
             source_tokens_lookup: create_source_lookup(),
-            monitor: monitor_files(file_paths, file_monitor_poll)?,
+            diagnostics: Diagnostics::default(),
             initially_parsed: false
-        };
-        
-        Ok(source_files)
+        }
     }
 
     pub fn lookup(&self, location: &SourceLocation) -> Option<&String> {    
         self.source_tokens_lookup.get(location) 
     }
 
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
+
     pub fn process(&mut self) -> Result<SourceChanges, RuxError> {
         if !self.initially_parsed {
             self.parse_source_locations_recurisvely()
@@ -79,36 +123,65 @@ impl SourceFiles {
 
     fn parse_source_locations_recurisvely(&mut self) -> Result<SourceChanges, RuxError> {   
         let mut changes = create_source_changes();
-        let source_location_walker = create_file_system_source_location_walker();
-
-        for location in source_location_walker.walk(&mut self.file_paths).unwrap() {
-            self.parse_source(location.clone())?;                   
-            changes.push(location);
-        }    
-        
+        let mut diagnostics = Diagnostics::default();
+        if let Err(error) = self.rescan_sources(&mut changes, &mut diagnostics) {
+            diagnostics.push(Diagnostic::error(format!("could not scan sources {}", error)));
+        }
+        self.diagnostics = diagnostics;
         self.initially_parsed = true;
 
         Ok(changes)
     }
 
+    fn rescan_sources(&mut self, changes: &mut SourceChanges, diagnostics: &mut Diagnostics) -> Result<(), SourceLocationWalkerError> {
+        let locations = self.source_location_walker.walk(&self.file_paths)?;
+        let removed_locations = self.source_tokens_lookup.keys()
+            .filter(|location| !locations.contains(location))
+            .cloned()
+            .collect::<Vec<SourceLocation>>();
+        for location in removed_locations {
+            self.delete_source(location.clone());
+            changes.push(location);
+        }
+        for location in locations {
+            self.reload_source(location, changes, diagnostics);
+        }
+        Ok(())
+    }
+
     fn process_changes(&mut self) -> Result<SourceChanges, RuxError> {
-        match self.monitor.try_get_file_changed() {
-            Ok(event) => match event {
-                FileMonitorFileChange::Modify(location) => {
-                    self.parse_source(location.clone())?;
-                    Ok(SourceChanges::from(location))
-                },
-                FileMonitorFileChange::Delete(location) => {
-                    self.delete_source(location.clone());
-                    Ok(SourceChanges::from(location))
-                },
-                FileMonitorFileChange::Create(location) => {
-                    self.parse_source(location.clone())?;
-                    Ok(SourceChanges::from(location)) 
-                }
+        let mut changes = create_source_changes();
+        let mut diagnostics = Diagnostics::default();
+        loop {
+            match self.monitor.try_get_file_changed() {
+                Ok(change) => self.apply_change(change, &mut changes, &mut diagnostics),
+                Err(FileMonitorWatchError::NoFileChanges) => break,
+                Err(FileMonitorWatchError::NoLongerMonitoring) => return Err(FileMonitorWatchError::NoLongerMonitoring.into()),
+                Err(error) => diagnostics.push(Diagnostic::error(format!("file monitor error {}", error)))
+            }
+        }
+        if !changes.is_empty() || !diagnostics.is_empty() {
+            self.diagnostics = diagnostics;
+        }
+        Ok(changes)
+    }
+
+    fn apply_change(&mut self, change: FileMonitorFileChange, changes: &mut SourceChanges, diagnostics: &mut Diagnostics) {
+        match change {
+            FileMonitorFileChange::Create(location) | FileMonitorFileChange::Modify(location) =>
+                self.reload_source(location, changes, diagnostics),
+            FileMonitorFileChange::Delete(location) => {
+                self.delete_source(location.clone());
+                changes.push(location);
             },
-            Err(FileMonitorWatchError::NoFileChanges) => Ok(create_source_changes()),
-            Err(error) => Err(error.into())
+            FileMonitorFileChange::Rename(from, to) => {
+                self.delete_source(from.clone());
+                changes.push(from);
+                self.reload_source(to, changes, diagnostics);
+            },
+            FileMonitorFileChange::Rescan => if let Err(error) = self.rescan_sources(changes, diagnostics) {
+                diagnostics.push(Diagnostic::error(format!("could not rescan sources, keeping current sources {}", error)));
+            }
         }
     }
 
@@ -116,6 +189,13 @@ impl SourceFiles {
         self.source_tokens_lookup.remove(&location);     
     }
 
+    fn reload_source(&mut self, location: SourceLocation, changes: &mut SourceChanges, diagnostics: &mut Diagnostics) {
+        match self.parse_source(location.clone()) {
+            Ok(()) => changes.push(location),
+            Err(error) => diagnostics.push(Diagnostic::error(format!("could not read {}, keeping previous source: {}", location, error)))
+        }
+    }
+
     fn parse_source(&mut self, location: SourceLocation) -> Result<(), SourceReaderError> {    
         let source_text = self.source_reader.read_source_at_location(&location)?;
         debug!("Source is now {:?} chars", source_text.len());
@@ -11,12 +11,22 @@ pub enum FileMonitorFileChange {
     Create(SourceLocation),
     Modify(SourceLocation),
     Delete(SourceLocation),
+    Rename(SourceLocation, SourceLocation),
+    Rescan
 }
 
 pub trait FileMonitor {
     fn try_get_file_changed(&self) -> Result<FileMonitorFileChange, FileMonitorWatchError>;
 }
 
+pub struct StaticFileMonitor;
+
+impl FileMonitor for StaticFileMonitor {
+    fn try_get_file_changed(&self) -> Result<FileMonitorFileChange, FileMonitorWatchError> {
+        Err(FileMonitorWatchError::NoFileChanges)
+    }
+}
+
 pub struct FileSystemFileMonitor {
     #[allow(dead_code)]
     watcher: RecommendedWatcher,
@@ -39,20 +49,40 @@ impl FileSystemFileMonitor {
     }
 }
 
+impl FileSystemFileMonitor {
+    pub(crate) fn produce_file_change(event: DebouncedEvent) -> Result<Option<FileMonitorFileChange>, FileMonitorWatchError> {
+        match event {
+            DebouncedEvent::Create(path) if is_source_path(&path) => Ok(Some(FileMonitorFileChange::Create(path.to_canonicalised_source_location()?))),
+            DebouncedEvent::Write(path) | DebouncedEvent::Chmod(path) if is_source_path(&path) => {
+                Ok(Some(FileMonitorFileChange::Modify(path.to_canonicalised_source_location()?)))
+            },
+            DebouncedEvent::Remove(path) if is_source_path(&path) => Ok(Some(FileMonitorFileChange::Delete(path.to_canonicalised_source_location()?))),
+            DebouncedEvent::Rename(from, to) => match (is_source_path(&from), is_source_path(&to)) {
+                (true, true) => Ok(Some(FileMonitorFileChange::Rename(from.to_canonicalised_source_location()?, to.to_canonicalised_source_location()?))),
+                (true, false) => Ok(Some(FileMonitorFileChange::Delete(from.to_canonicalised_source_location()?))),
+                (false, true) => Ok(Some(FileMonitorFileChange::Create(to.to_canonicalised_source_location()?))),
+                (false, false) => Ok(None)
+            },
+            DebouncedEvent::Rescan => Ok(Some(FileMonitorFileChange::Rescan)),
+            DebouncedEvent::Error(error, path) => Err(FileMonitorWatchError::WatchError(error.to_string(), path)),
+            _ => Ok(None)
+        }
+    }
+}
+
 impl FileMonitor for FileSystemFileMonitor {
     fn try_get_file_changed(&self) -> Result<FileMonitorFileChange, FileMonitorWatchError> {
-        match self.rx.try_recv() {
-            Ok(event) => {
-                match event {
-                    DebouncedEvent::Create(path) => Ok(FileMonitorFileChange::Create(path.to_canonicalised_source_location()?)),
-                    DebouncedEvent::Write(path) => Ok(FileMonitorFileChange::Modify(path.to_canonicalised_source_location()?)),
-                    DebouncedEvent::NoticeRemove(path) => Ok(FileMonitorFileChange::Delete(path.to_canonicalised_source_location()?)),
-                    _ => Err(FileMonitorWatchError::NoFileChanges)
+        loop {
+            match self.rx.try_recv() {
+                Ok(event) => {
+                    if let Some(change) = Self::produce_file_change(event)? {
+                        return Ok(change);
+                    }
+                },
+                Err(err) => return match err {
+                    TryRecvError::Empty => Err(FileMonitorWatchError::NoFileChanges),
+                    TryRecvError::Disconnected => Err(FileMonitorWatchError::NoLongerMonitoring),
                 }
-            },
-            Err(err) => match err {
-                TryRecvError::Empty => Err(FileMonitorWatchError::NoFileChanges),
-                TryRecvError::Disconnected => Err(FileMonitorWatchError::NoLongerMonitoring),
             }
         }
     }
@@ -1,35 +1,85 @@
 use crate::prelude::*;
 use std::fs;
 
-pub fn create_file_paths(relative_folder_path: &'static str) -> FilePaths {
-    FilePaths::new(relative_folder_path)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum FilePathBase {
+    WorkingDirectory,
+    Executable
 }
-#[derive(Clone, Copy)]
+
+#[derive(Debug, Clone)]
 pub struct FilePaths {
-    relative_folder_path: &'static str
+    folder_path: PathBuf,
+    base: FilePathBase,
+    environment_override: Option<String>
 }
 
 impl FilePaths {
-    pub fn new(relative_folder_path: &'static str) -> Self {
+    pub fn new(folder_path: impl Into<PathBuf>) -> Self {
         FilePaths {
-            relative_folder_path
+            folder_path: folder_path.into(),
+            base: FilePathBase::WorkingDirectory,
+            environment_override: None
         }
     }
 
+    pub fn with_folder_path(mut self, folder_path: impl Into<PathBuf>) -> Self {
+        self.folder_path = folder_path.into();
+        self
+    }
+
+    pub fn with_base(mut self, base: FilePathBase) -> Self {
+        self.base = base;
+        self
+    }
+
+    pub fn with_environment_override(mut self, variable: impl Into<String>) -> Self {
+        self.environment_override = Some(variable.into());
+        self
+    }
+
     pub fn get_absolute_folder_path(&self) -> Result<PathBuf, FilePathError> {
-        let path = std::env::var("CARGO_MANIFEST_DIR").map_err(|_|FilePathError::ManifestDirectoryEnvironmentVariableNotSet)?;
-        info!("manifest path is {:?}", path);
-        Ok(PathBuf::from(path).join(self.relative_folder_path))
+        let folder_path = match self.get_overridden_folder_path() {
+            Some(path) => return Ok(Self::get_working_directory()?.join(path)),
+            None => &self.folder_path
+        };
+        if folder_path.is_absolute() {
+            return Ok(folder_path.clone());
+        }
+        let base_path = match self.base {
+            FilePathBase::WorkingDirectory => Self::get_working_directory()?,
+            FilePathBase::Executable => Self::get_executable_directory()?
+        };
+        info!("resolving rux folder {:?} against {:?}", folder_path, base_path);
+        Ok(base_path.join(folder_path))
+    }
+
+    fn get_overridden_folder_path(&self) -> Option<PathBuf> {
+        let variable = self.environment_override.as_ref()?;
+        match std::env::var_os(variable) {
+            Some(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => None
+        }
+    }
+
+    fn get_working_directory() -> Result<PathBuf, FilePathError> {
+        std::env::current_dir().map_err(|_| FilePathError::WorkingDirectoryNotFound)
+    }
+
+    fn get_executable_directory() -> Result<PathBuf, FilePathError> {
+        let executable = std::env::current_exe().map_err(|_| FilePathError::ExecutableDirectoryNotFound)?;
+        match executable.parent() {
+            Some(directory) => Ok(directory.to_path_buf()),
+            None => Err(FilePathError::ExecutableDirectoryNotFound)
+        }
     }
 }
 
 impl Default for FilePaths {
     fn default() -> Self {
-        Self {
-            relative_folder_path: ""
-        }
+        Self::new("")
     }
- }
+}
 
 #[derive(Debug)]
 pub enum Error {
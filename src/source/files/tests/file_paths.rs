@@ -0,0 +1,32 @@
+use crate::prelude::*;
+
+#[test]
+fn relative_folder_path_resolves_against_working_directory() {
+    let paths = FilePaths::new("assets/ui");
+    assert_eq!(std::env::current_dir().unwrap().join("assets/ui"), paths.get_absolute_folder_path().unwrap());
+}
+
+#[test]
+fn relative_folder_path_resolves_against_executable_directory() {
+    let paths = FilePaths::new("assets/ui").with_base(FilePathBase::Executable);
+    let executable_directory = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    assert_eq!(executable_directory.join("assets/ui"), paths.get_absolute_folder_path().unwrap());
+}
+
+#[test]
+fn absolute_folder_path_ignores_base() {
+    let folder = std::env::temp_dir().join("rux");
+    let paths = FilePaths::new(folder.clone()).with_base(FilePathBase::Executable);
+    assert_eq!(folder, paths.get_absolute_folder_path().unwrap());
+}
+
+#[test]
+fn environment_override_replaces_folder_path_when_set() {
+    let folder = std::env::temp_dir().join("rux-override");
+    std::env::set_var("RUXY_TEST_FOLDER_OVERRIDE", &folder);
+    let paths = FilePaths::new("assets/ui").with_environment_override("RUXY_TEST_FOLDER_OVERRIDE");
+    assert_eq!(folder, paths.get_absolute_folder_path().unwrap());
+
+    let paths = FilePaths::new("assets/ui").with_environment_override("RUXY_TEST_FOLDER_OVERRIDE_UNSET");
+    assert_eq!(std::env::current_dir().unwrap().join("assets/ui"), paths.get_absolute_folder_path().unwrap());
+}
\ No newline at end of file
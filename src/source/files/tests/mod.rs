@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod file_paths;
+#[cfg(test)]
+mod monitoring;
\ No newline at end of file
@@ -0,0 +1,156 @@
+use notify::DebouncedEvent;
+
+use crate::prelude::*;
+
+fn processed_locations(source_files: &mut SourceFiles) -> Vec<SourceLocation> {
+    source_files.process().unwrap().iter().cloned().collect()
+}
+
+struct TemporaryFolder(PathBuf);
+
+impl TemporaryFolder {
+    fn new(name: &str) -> Self {
+        let folder = std::env::temp_dir().join(format!("ruxy-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        Self(folder)
+    }
+}
+
+impl Drop for TemporaryFolder {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn initialised_source_files(file_system: &MemoryFileSystem) -> SourceFiles {
+    let mut source_files = file_system.source_files();
+    source_files.process().unwrap();
+    source_files
+}
+
+#[test]
+fn pending_changes_are_drained_and_coalesced_in_one_process() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.write("app.rux", "<root><label /></root>");
+    file_system.write("panel.rux", "<control />");
+    file_system.write("app.rux", "<root><panel /></root>");
+
+    assert_eq!(vec!(MemoryFileSystem::location("app.rux"), MemoryFileSystem::location("panel.rux")), processed_locations(&mut source_files));
+    assert_eq!(Some(&String::from("<root><panel /></root>")), source_files.lookup(&MemoryFileSystem::location("app.rux")));
+    assert!(source_files.process().unwrap().is_empty());
+}
+
+#[test]
+fn rename_is_handled_as_delete_and_create() {
+    let file_system = MemoryFileSystem::default().with_source("old.rux", "<control />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.rename("old.rux", "new.rux");
+
+    assert_eq!(vec!(MemoryFileSystem::location("old.rux"), MemoryFileSystem::location("new.rux")), processed_locations(&mut source_files));
+    assert_eq!(None, source_files.lookup(&MemoryFileSystem::location("old.rux")));
+    assert_eq!(Some(&String::from("<control />")), source_files.lookup(&MemoryFileSystem::location("new.rux")));
+}
+
+#[test]
+fn rescan_reparses_every_source_and_removes_missing_sources() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />").with_source("old.rux", "<control />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.clone().with_source("added.rux", "<control />").without_source("old.rux");
+    file_system.rescan();
+
+    let locations = processed_locations(&mut source_files);
+    assert_eq!(3, locations.len());
+    assert!(locations.contains(&MemoryFileSystem::location("added.rux")));
+    assert_eq!(None, source_files.lookup(&MemoryFileSystem::location("old.rux")));
+    assert!(source_files.lookup(&MemoryFileSystem::location("added.rux")).is_some());
+}
+
+#[test]
+fn rescan_of_removed_folder_keeps_current_sources() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.remove_folder();
+    file_system.rescan();
+
+    assert!(processed_locations(&mut source_files).is_empty());
+    assert!(source_files.lookup(&MemoryFileSystem::location("app.rux")).is_some());
+}
+
+#[test]
+fn watcher_errors_do_not_stop_draining() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.report_error("watch failed", None);
+    file_system.write("app.rux", "<root><label /></root>");
+
+    assert_eq!(vec!(MemoryFileSystem::location("app.rux")), processed_locations(&mut source_files));
+}
+
+#[test]
+fn watcher_errors_are_reported_as_diagnostics() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+
+    file_system.report_error("permission denied", Some("memory/app.rux"));
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+
+    let diagnostics = ast.diagnostics().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>();
+    assert_eq!(vec!("file monitor error memory/app.rux: permission denied"), diagnostics);
+
+    file_system.write("app.rux", "<root />");
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+    assert_eq!(0, ast.diagnostics().count());
+}
+
+#[test]
+fn failed_rescan_is_reported_as_a_diagnostic() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.remove_folder();
+    file_system.rescan();
+    source_files.process().unwrap();
+
+    assert_eq!(1, source_files.diagnostics().len());
+}
+
+#[test]
+fn failed_reload_is_reported_as_a_diagnostic() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root />");
+    let mut source_files = initialised_source_files(&file_system);
+
+    file_system.rename("missing.rux", "renamed.rux");
+    source_files.process().unwrap();
+
+    let diagnostics = source_files.diagnostics().iter().map(|diagnostic| diagnostic.to_string()).collect::<Vec<String>>();
+    assert_eq!(vec!("could not read memory/renamed.rux, keeping previous source: error reading source"), diagnostics);
+    assert_eq!(Some(&String::from("<root />")), source_files.lookup(&MemoryFileSystem::location("app.rux")));
+}
+
+#[test]
+fn file_system_events_for_temporary_files_are_mapped_to_source_changes() {
+    let temporary_folder = TemporaryFolder::new("monitoring-test");
+    let folder = &temporary_folder.0;
+    let source = folder.join("app.rux");
+    std::fs::write(&source, "<root />").unwrap();
+    let temporary = folder.join("app.rux.tmp");
+
+    let change = FileSystemFileMonitor::produce_file_change(DebouncedEvent::Rename(temporary.clone(), source.clone())).unwrap();
+    assert!(matches!(change, Some(FileMonitorFileChange::Create(location)) if location == source.to_canonicalised_source_location().unwrap()));
+    assert!(FileSystemFileMonitor::produce_file_change(DebouncedEvent::Write(temporary)).unwrap().is_none());
+
+    let removed = folder.join("removed.rux");
+    let change = FileSystemFileMonitor::produce_file_change(DebouncedEvent::Remove(removed)).unwrap();
+    assert!(matches!(change, Some(FileMonitorFileChange::Delete(_))));
+    assert!(matches!(FileSystemFileMonitor::produce_file_change(DebouncedEvent::Rescan), Ok(Some(FileMonitorFileChange::Rescan))));
+}
\ No newline at end of file
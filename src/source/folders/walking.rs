@@ -2,6 +2,12 @@ use walkdir::WalkDir;
 
 use crate::prelude::*;
 
+pub const SOURCE_FILE_EXTENSION: &str = "rux";
+
+pub fn is_source_path(path: &Path) -> bool {
+    matches!(path.extension(), Some(extension) if extension == SOURCE_FILE_EXTENSION)
+}
+
 pub trait SourceLocationWalker<T> where T: IntoIterator<Item=SourceLocation> {
     fn walk(&self, paths: &FilePaths) -> Result<T, SourceLocationWalkerError>;
 }
@@ -15,46 +21,39 @@ pub struct FileSystemSourceLocationWalker;
 
 #[derive(Debug)]
 pub enum SourceLocationWalkerError {
-    InvalidRootPath
+    InvalidRootPath,
+    WalkError(String),
+    SourceLocationError(SourceLocationError)
 }
 
-impl SourceLocationWalker<FileSystemSourceLocationIterator> for FileSystemSourceLocationWalker {
-    fn walk(&self, paths: &FilePaths) -> Result<FileSystemSourceLocationIterator, SourceLocationWalkerError> {
-        debug!("walking tree");
-        if let Ok(root_path) = paths.get_absolute_folder_path() {
-            return Ok(
-                FileSystemSourceLocationIterator {
-                    walk_dir: WalkDir::new(root_path).into_iter()
-                }
-            );
+impl std::fmt::Display for SourceLocationWalkerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceLocationWalkerError::InvalidRootPath => write!(f, "invalid source folder"),
+            SourceLocationWalkerError::WalkError(message) => write!(f, "{}", message),
+            SourceLocationWalkerError::SourceLocationError(_) => write!(f, "source location does not exist")
         }
-
-        Err(SourceLocationWalkerError::InvalidRootPath)        
     }
 }
 
-pub struct FileSystemSourceLocationIterator {
-    walk_dir: walkdir::IntoIter
+impl From<SourceLocationError> for SourceLocationWalkerError {
+    fn from(error: SourceLocationError) -> SourceLocationWalkerError {
+        SourceLocationWalkerError::SourceLocationError(error)
+    }
 }
 
-impl Iterator for FileSystemSourceLocationIterator {
-    type Item = SourceLocation;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let entry= self.walk_dir.next()?;
-
-            if let Ok(entry) = entry { 
-                let path = PathBuf::from(entry.path());
-                if let Some(extension) = path.extension() {
-                    if extension == "rux" {
-                        let location = path.to_canonicalised_source_location().unwrap();
-                        return Some(location)
-                    }
-                }
-            } else {
-                return None;  
+impl SourceLocationWalker<Vec<SourceLocation>> for FileSystemSourceLocationWalker {
+    fn walk(&self, paths: &FilePaths) -> Result<Vec<SourceLocation>, SourceLocationWalkerError> {
+        debug!("walking tree");
+        let root_path = paths.get_absolute_folder_path().map_err(|_| SourceLocationWalkerError::InvalidRootPath)?;
+        let mut locations = vec!();
+        for entry in WalkDir::new(root_path) {
+            let entry = entry.map_err(|error| SourceLocationWalkerError::WalkError(error.to_string()))?;
+            let path = PathBuf::from(entry.path());
+            if is_source_path(&path) {
+                locations.push(path.to_canonicalised_source_location()?);
             }
-        }          
+        }
+        Ok(locations)
     }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::prelude::*;
+
+pub const MEMORY_SOURCE_ROOT: &str = "memory";
+
+#[derive(Default)]
+struct MemoryFileSystemState {
+    sources: Vec<(SourceLocation, String)>,
+    changes: VecDeque<Result<FileMonitorFileChange, FileMonitorWatchError>>,
+    folder_removed: bool
+}
+
+#[derive(Clone, Default)]
+pub struct MemoryFileSystem {
+    state: Rc<RefCell<MemoryFileSystemState>>
+}
+
+impl MemoryFileSystem {
+    pub fn location(path: &str) -> SourceLocation {
+        PathBuf::from(MEMORY_SOURCE_ROOT).join(path).to_normalised_source_location()
+    }
+
+    pub fn with_source(self, path: &str, source: &str) -> Self {
+        self.insert_source(Self::location(path), source);
+        self
+    }
+
+    pub fn without_source(self, path: &str) -> Self {
+        let location = Self::location(path);
+        self.state.borrow_mut().sources.retain(|(existing, _)| existing != &location);
+        self
+    }
+
+    pub fn write(&self, path: &str, source: &str) {
+        let location = Self::location(path);
+        let change = match self.insert_source(location.clone(), source) {
+            true => FileMonitorFileChange::Modify(location),
+            false => FileMonitorFileChange::Create(location)
+        };
+        self.push_change(Ok(change));
+    }
+
+    pub fn remove(&self, path: &str) {
+        let location = Self::location(path);
+        self.state.borrow_mut().sources.retain(|(existing, _)| existing != &location);
+        self.push_change(Ok(FileMonitorFileChange::Delete(location)));
+    }
+
+    pub fn rename(&self, from: &str, to: &str) {
+        let (from, to) = (Self::location(from), Self::location(to));
+        let mut state = self.state.borrow_mut();
+        if let Some((location, _)) = state.sources.iter_mut().find(|(existing, _)| existing == &from) {
+            *location = to.clone();
+        }
+        state.changes.push_back(Ok(FileMonitorFileChange::Rename(from, to)));
+    }
+
+    pub fn rescan(&self) {
+        self.push_change(Ok(FileMonitorFileChange::Rescan));
+    }
+
+    pub fn remove_folder(&self) {
+        self.state.borrow_mut().folder_removed = true;
+    }
+
+    pub fn report_error(&self, message: &str, path: Option<&str>) {
+        self.push_change(Err(FileMonitorWatchError::WatchError(message.to_string(), path.map(PathBuf::from))));
+    }
+
+    pub fn source_files(&self) -> SourceFiles {
+        SourceFiles::from_backends(FilePaths::default(), Box::new(self.clone()), Box::new(self.clone()), Box::new(self.clone()))
+    }
+
+    fn push_change(&self, change: Result<FileMonitorFileChange, FileMonitorWatchError>) {
+        self.state.borrow_mut().changes.push_back(change);
+    }
+
+    fn insert_source(&self, location: SourceLocation, source: &str) -> bool {
+        let mut state = self.state.borrow_mut();
+        match state.sources.iter_mut().find(|(existing, _)| existing == &location) {
+            Some((_, existing_source)) => {
+                *existing_source = source.to_string();
+                true
+            },
+            None => {
+                state.sources.push((location, source.to_string()));
+                false
+            }
+        }
+    }
+}
+
+impl SourceReader for MemoryFileSystem {
+    fn read_source_at_location(&self, location: &SourceLocation) -> Result<String, SourceReaderError> {
+        self.state.borrow().sources.iter()
+            .find(|(existing, _)| existing == location)
+            .map(|(_, source)| source.clone())
+            .ok_or(SourceReaderError::ErrorReadingSource)
+    }
+}
+
+impl SourceLocationWalker<Vec<SourceLocation>> for MemoryFileSystem {
+    fn walk(&self, _paths: &FilePaths) -> Result<Vec<SourceLocation>, SourceLocationWalkerError> {
+        let state = self.state.borrow();
+        if state.folder_removed {
+            return Err(SourceLocationWalkerError::InvalidRootPath);
+        }
+        Ok(state.sources.iter().map(|(location, _)| location.clone()).collect())
+    }
+}
+
+impl FileMonitor for MemoryFileSystem {
+    fn try_get_file_changed(&self) -> Result<FileMonitorFileChange, FileMonitorWatchError> {
+        self.state.borrow_mut().changes.pop_front().unwrap_or(Err(FileMonitorWatchError::NoFileChanges))
+    }
+}
\ No newline at end of file
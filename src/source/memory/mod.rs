@@ -0,0 +1,3 @@
+mod files;
+
+pub use files::*;
\ No newline at end of file
@@ -1,9 +1,16 @@
 mod tokenization;
 mod source;
+mod spans;
 mod files;
 mod folders;
+mod embedded;
+mod tests;
+mod memory;
 
 pub use source::*;
+pub use spans::*;
 pub use tokenization::*;
 pub use files::*;
-pub use folders::*;
\ No newline at end of file
+pub use folders::*;
+pub use embedded::*;
+pub use memory::*;
\ No newline at end of file
@@ -1,33 +1,114 @@
 
+use std::path::Component;
+
 use crate::prelude::*;
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+const IMPORT_PATH_SEPARATORS: [char; 2] = ['/', '\\'];
+
+#[derive(Clone, Debug, Default)]
 pub struct SourceLocation {
-    pub location: Option<String>
+    path: Option<PathBuf>,
+    key: String
 }
 
 impl SourceLocation {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            key: normalise_location_key(&path),
+            path: Some(path)
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
     pub fn to_relative_location(&self, relative_location: &str) -> Result<SourceLocation, SourceLocationError> {
-        self.to_path_buf()
-            .parent().unwrap()
-            .join(relative_location)
-            .to_canonicalised_source_location()
-        
+        let mut path = self.to_path_buf()
+            .parent()
+            .ok_or(SourceLocationError::DoesNotExist)?
+            .to_path_buf();
+        relative_location.split(IMPORT_PATH_SEPARATORS)
+            .filter(|part| !part.is_empty())
+            .for_each(|part| path.push(part));
+        match path.to_canonicalised_source_location() {
+            Err(SourceLocationError::DoesNotExist) if path.is_relative() => Ok(path.to_normalised_source_location()),
+            result => result
+        }
+    }
+}
+
+fn normalise_location_key(path: &Path) -> String {
+    let mut parts: Vec<String> = vec!();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::RootDir if parts.is_empty() => parts.push(String::new()),
+            Component::RootDir => {},
+            Component::ParentDir => match parts.last() {
+                Some(part) if !part.is_empty() && part != ".." => {
+                    parts.pop();
+                },
+                _ => parts.push(String::from(".."))
+            },
+            other => parts.push(other.as_os_str().to_string_lossy().into_owned())
+        }
+    }
+    let key = parts.join("/");
+    match cfg!(target_os = "windows") {
+        true => key.to_lowercase(),
+        false => key
+    }
+}
+
+impl PartialEq for SourceLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SourceLocation {}
+
+impl std::hash::Hash for SourceLocation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialOrd for SourceLocation {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SourceLocation {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display()),
+            None => Ok(())
+        }
     }
 }
 
 impl From<&str> for SourceLocation {
     fn from(from: &str) -> Self {
-        Self { location: Some(from.to_owned()) }
+        Self::new(PathBuf::from(from))
     }
 }
 
 impl From<SourceLocation> for String {
     fn from(from: SourceLocation) -> Self {
-        match from.location {
-            Some(value) => value.to_owned(),
-            None => String::default()
-        }
+        from.to_string()
     }
 }
 
@@ -36,6 +117,14 @@ pub enum SourceReaderError {
     ErrorReadingSource
 }
 
+impl std::fmt::Display for SourceReaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceReaderError::ErrorReadingSource => write!(f, "error reading source")
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum SourceLocationError {
     DoesNotExist
@@ -0,0 +1,125 @@
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Hash, Debug)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize
+}
+
+impl SourcePosition {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self {
+            line,
+            column
+        }
+    }
+}
+
+impl Default for SourcePosition {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, PartialOrd, Hash, Debug, Default)]
+pub struct SourceSpan {
+    location: SourceLocation,
+    start: SourcePosition,
+    end: SourcePosition
+}
+
+impl SourceSpan {
+    pub fn new(location: SourceLocation, start: SourcePosition, end: SourcePosition) -> Self {
+        Self {
+            location,
+            start,
+            end
+        }
+    }
+
+    pub fn point(location: SourceLocation, position: SourcePosition) -> Self {
+        Self::new(location, position, position)
+    }
+
+    pub fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+
+    pub fn start(&self) -> SourcePosition {
+        self.start
+    }
+
+    pub fn end(&self) -> SourcePosition {
+        self.end
+    }
+}
+
+impl std::fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.location.path() {
+            Some(_) => write!(f, "{}:{}:{}", self.location, self.start.line, self.start.column),
+            None => write!(f, "{}:{}", self.start.line, self.start.column)
+        }
+    }
+}
+
+pub struct SourcePositions {
+    location: SourceLocation,
+    origin: SourcePosition,
+    line_starts: Vec<usize>,
+    wide_characters: Vec<(usize, usize)>
+}
+
+impl SourcePositions {
+    pub fn new(location: SourceLocation, origin: SourcePosition) -> Self {
+        Self {
+            location,
+            origin,
+            line_starts: vec!(0),
+            wide_characters: vec!()
+        }
+    }
+
+    pub fn advance(&mut self, index: usize, character: char) {
+        if character == '\n' {
+            self.line_starts.push(index + 1);
+        }
+        if character.len_utf8() > 1 {
+            self.wide_characters.push((index, character.len_utf8() - 1));
+        }
+    }
+
+    pub fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+
+    pub fn position(&self, index: usize) -> SourcePosition {
+        let line = self.line_starts.partition_point(|line_start| *line_start <= index);
+        let line_start = self.line_starts[line - 1];
+        let column = index - line_start - self.wide_character_bytes(line_start, index);
+        if line == 1 {
+            return SourcePosition::new(self.origin.line, self.origin.column + column);
+        }
+        SourcePosition::new(self.origin.line + line - 1, column + 1)
+    }
+
+    fn wide_character_bytes(&self, from: usize, to: usize) -> usize {
+        let first = self.wide_characters.partition_point(|(index, _)| *index < from);
+        let last = self.wide_characters.partition_point(|(index, _)| *index < to);
+        self.wide_characters[first..last].iter().map(|(_, extra_bytes)| extra_bytes).sum()
+    }
+
+    pub fn span(&self, start: usize, end: usize) -> SourceSpan {
+        SourceSpan::new(self.location.clone(), self.position(start), self.position(end))
+    }
+
+    pub fn point(&self, index: usize) -> SourceSpan {
+        SourceSpan::point(self.location.clone(), self.position(index))
+    }
+}
+
+impl Default for SourcePositions {
+    fn default() -> Self {
+        Self::new(SourceLocation::default(), SourcePosition::default())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+
+fn example_locations() -> Vec<SourceLocation> {
+    let paths = FilePaths::new("examples/assets/first").with_base(FilePathBase::WorkingDirectory);
+    let mut locations = create_file_system_source_location_walker().walk(&paths).unwrap();
+    locations.sort();
+    locations
+}
+
+#[test]
+fn locations_compare_by_normalised_key() {
+    assert_eq!(SourceLocation::from("controls/panel.rux"), SourceLocation::from("./controls/../controls/panel.rux"));
+    assert_eq!("controls/panel.rux", SourceLocation::from("controls/./panel.rux").key());
+    assert_eq!("../panel.rux", SourceLocation::from("../panel.rux").key());
+    assert_ne!(SourceLocation::from("controls/panel.rux"), SourceLocation::from("panel.rux"));
+}
+
+#[test]
+fn import_paths_resolve_identically_with_either_separator() {
+    let app = SourceLocation::from("memory/app.rux");
+    let forward = app.to_relative_location("./controls/panel.rux").unwrap();
+    let backward = app.to_relative_location(".\\controls\\panel.rux").unwrap();
+    assert_eq!(forward, backward);
+    assert_eq!("memory/controls/panel.rux", forward.key());
+}
+
+#[test]
+fn walked_file_system_locations_can_be_read_and_resolved() {
+    let locations = example_locations();
+    let app = locations.iter().find(|location| location.key().ends_with("/app.rux")).unwrap();
+    let selections = locations.iter().find(|location| location.key().ends_with("/selections.rux")).unwrap();
+
+    assert!(create_source_file_reader().read_source_at_location(app).is_ok());
+    assert_eq!(Ok(selections.clone()), app.to_relative_location("./selections.rux"));
+    assert_eq!(Ok(selections.clone()), app.to_relative_location(".\\selections.rux"));
+}
\ No newline at end of file
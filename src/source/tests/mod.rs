@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod locations;
\ No newline at end of file
@@ -1,265 +1,457 @@
 use crate::prelude::*;
 
-use std::iter::Enumerate;
-use std::str::Chars;
+use std::collections::VecDeque;
+use std::str::CharIndices;
 
 #[derive(PartialEq, Eq, Debug)]
 enum CodeState {
     Start,
-    StartFunction,
-    InFunction(usize),
-    StartValue,
-    InSignedNumberValue(usize),
-    InUSizeNumberValue(usize),
-    InStringValue(usize),
+    StartToken,
+    InIdentifier(usize),
+    InNumberValue(usize),
+    InStringValue(usize, char, bool),
     InVariableValue(usize),
-    EndValue,
-    EndFunction,
-    End,
-    InWhitespace
+    InColourValue(usize),
+    InOperator(usize, char),
+    End
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum CodeParenthesis {
+    Function,
+    Group
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Debug, Clone, Copy)]
+pub enum CodeOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    And,
+    Or,
+    Not,
+    Negate
+}
 
 #[derive(PartialEq, PartialOrd, Debug, Clone)]
 pub enum CodeTokenPropertyValue {
     StartFunction(String),
     PropertyValue(SourceTokenPropertyValue),
-    EndFunction
+    ArgumentSeparator,
+    EndFunction,
+    StartGroup,
+    EndGroup,
+    Operator(CodeOperator),
+    Conditional,
+    ConditionalElse
 }
 
 #[derive(PartialEq, Eq, Debug, PartialOrd, Clone)]
 pub enum CodeTokenError {
-    NoOpeningBrace(usize),
-    NoClosingBrace(usize),
-    NoOpeningFunctionParenthesis(usize),
-    NoClosingFunctionParenthesis(usize),
-    ParseNumberError(usize, String),
+    NoOpeningBrace(SourceSpan),
+    NoClosingBrace(SourceSpan),
+    NoOpeningFunctionParenthesis(SourceSpan),
+    NoClosingFunctionParenthesis(SourceSpan),
+    ParseNumberError(SourceSpan, String),
+    InvalidStringEscape(SourceSpan),
+    UnknownIdentifier(SourceSpan, String),
+    ParseColourError(SourceSpan, String),
+    UnknownOperator(SourceSpan, String),
+    UnexpectedCharacter(SourceSpan, char)
+}
+
+impl CodeTokenError {
+    pub fn span(&self) -> &SourceSpan {
+        match self {
+            Self::NoOpeningBrace(span) |
+            Self::NoClosingBrace(span) |
+            Self::NoOpeningFunctionParenthesis(span) |
+            Self::NoClosingFunctionParenthesis(span) |
+            Self::ParseNumberError(span, _) |
+            Self::InvalidStringEscape(span) |
+            Self::UnknownIdentifier(span, _) |
+            Self::ParseColourError(span, _) |
+            Self::UnknownOperator(span, _) |
+            Self::UnexpectedCharacter(span, _) => span
+        }
+    }
+}
+
+impl std::fmt::Display for CodeTokenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoOpeningBrace(_) => write!(f, "expected '{{' to start code"),
+            Self::NoClosingBrace(_) => write!(f, "expected '}}' to close code"),
+            Self::NoOpeningFunctionParenthesis(_) => write!(f, "expected '(' after function name"),
+            Self::NoClosingFunctionParenthesis(_) => write!(f, "expected ')' to close function call"),
+            Self::ParseNumberError(_, value) => write!(f, "could not parse number value {:?}", value),
+            Self::InvalidStringEscape(_) => write!(f, "invalid string escape"),
+            Self::UnknownIdentifier(_, value) => write!(f, "unknown identifier {:?}", value),
+            Self::ParseColourError(_, value) => write!(f, "could not parse colour value {:?}", value),
+            Self::UnknownOperator(_, operator) => write!(f, "unknown operator {:?}", operator),
+            Self::UnexpectedCharacter(_, character) => write!(f, "unexpected character {:?}", character)
+        }
+    }
 }
 
 pub type CodeTokenResult = Result<CodeTokenPropertyValue, CodeTokenError>;
 pub type CodeTokenOption = Option<CodeTokenResult>;
 
-pub fn tokenize_code(from: &str) -> Vec::<CodeTokenResult> {
-    CodeTokenizer::from_string(from).collect()
+pub fn tokenize_code(from: &str, positions: SourcePositions) -> Vec::<CodeTokenResult> {
+    CodeTokenizer::from_string(from).with_positions(positions).collect()
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+fn is_identifier_start_character(character: char) -> bool {
+    character.is_alphabetic() || character == '_'
+}
+
+fn is_variable_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '.'
+}
+
+fn is_operator_character(character: char) -> bool {
+    "+-*/%=!<>&|".contains(character)
+}
+
+fn match_operator(operator: &str) -> Option<CodeOperator> {
+    match operator {
+        "+" => Some(CodeOperator::Add),
+        "-" => Some(CodeOperator::Subtract),
+        "*" => Some(CodeOperator::Multiply),
+        "/" => Some(CodeOperator::Divide),
+        "%" => Some(CodeOperator::Remainder),
+        "==" => Some(CodeOperator::Equal),
+        "!=" => Some(CodeOperator::NotEqual),
+        "<" => Some(CodeOperator::Less),
+        "<=" => Some(CodeOperator::LessOrEqual),
+        ">" => Some(CodeOperator::Greater),
+        ">=" => Some(CodeOperator::GreaterOrEqual),
+        "&&" => Some(CodeOperator::And),
+        "||" => Some(CodeOperator::Or),
+        "!" => Some(CodeOperator::Not),
+        _ => None
+    }
 }
 
 pub struct CodeTokenizer<'a>{
     input: &'a str,
-    characters: Enumerate<Chars<'a>>,
-    state: CodeState
+    characters: CharIndices<'a>,
+    positions: SourcePositions,
+    state: CodeState,
+    parentheses: Vec<CodeParenthesis>,
+    after_operand: bool,
+    pending: VecDeque<CodeTokenResult>
 }
 
 impl<'a> CodeTokenizer<'a> {
     pub fn from_string(input: &'a str) -> Self {
         Self {
             input,
-            characters: input.chars().enumerate(),
-            state: CodeState::Start
+            characters: input.char_indices(),
+            positions: SourcePositions::default(),
+            state: CodeState::Start,
+            parentheses: vec!(),
+            after_operand: false,
+            pending: VecDeque::new()
         }
     }
 
+    pub fn with_positions(mut self, positions: SourcePositions) -> Self {
+        self.positions = positions;
+        self
+    }
+
+    fn advance_and_transition(&mut self, index: usize, character: char) -> CodeTokenOption {
+        self.positions.advance(index, character);
+        self.transition(index, character)
+    }
+
     fn splice_input(&mut self, from: usize, to: usize) -> &'a str {
         &self.input[from..to]
     }
 
+    fn is_path_separator_at(&self, index: usize) -> bool {
+        if self.input[..index].ends_with(PATH_SEPARATOR_CHAR) {
+            return true;
+        }
+        let rest = &self.input[index..];
+        rest.starts_with(PATH_SEPARATOR) && rest[PATH_SEPARATOR.len()..].starts_with(is_identifier_start_character)
+    }
+
     fn start_if_possible(&mut self, index: usize, character: char) -> CodeTokenOption {
         if character == CODE_OPENING_CHAR {
-            self.state = CodeState::StartFunction;
+            self.state = CodeState::StartToken;
             return None;
         }
-        Some(Err(CodeTokenError::NoOpeningBrace(index)))
+        Some(Err(CodeTokenError::NoOpeningBrace(self.positions.point(index))))
     }
 
-    fn end_if_possible(&mut self, index: usize, character: char) -> CodeTokenOption {
-        if character == CODE_CLOSING_CHAR {
-            self.state = CodeState::End;
-            return Some(Ok(CodeTokenPropertyValue::EndFunction));
+    fn end_if_possible(&mut self, index: usize) -> CodeTokenOption {
+        self.state = CodeState::End;
+        if !self.parentheses.is_empty() {
+            return Some(Err(CodeTokenError::NoClosingFunctionParenthesis(self.positions.point(index))));
         }
-        Some(Err(CodeTokenError::NoClosingBrace(index)))
+        None
     }
 
-    fn start_function_if_possible(&mut self, index: usize, character: char) -> CodeTokenOption {
+    fn start_token_if_possible(&mut self, index: usize, character: char) -> CodeTokenOption {
+        if character.is_whitespace() {
+            return None;
+        }
+        if character == CODE_CLOSING_CHAR {
+            return self.end_if_possible(index);
+        }
         if character == FUNCTION_OPENING_BRACE {
-            self.state = CodeState::StartValue;
-        } else {
-            self.state = CodeState::InFunction(index);
+            self.parentheses.push(CodeParenthesis::Group);
+            return self.produce_punctuation_result(CodeTokenPropertyValue::StartGroup, false);
         }
-        None
-    }
-
-    fn start_value_if_possible(&mut self, index: usize, character: char) -> CodeTokenOption {
         if character == FUNCTION_CLOSING_BRACE {
-            self.state = CodeState::EndFunction;
-            return None;
+            return self.end_parenthesis_if_possible(index);
+        }
+        if character == ',' && self.parentheses.last() == Some(&CodeParenthesis::Function) {
+            return self.produce_punctuation_result(CodeTokenPropertyValue::ArgumentSeparator, false);
         }
-        if character.is_numeric() {
-            self.state = CodeState::InUSizeNumberValue(index);
+        if character == '?' {
+            return self.produce_punctuation_result(CodeTokenPropertyValue::Conditional, false);
+        }
+        if character == ':' {
+            return self.produce_punctuation_result(CodeTokenPropertyValue::ConditionalElse, false);
+        }
+        if character.is_numeric() || (character == '-' && !self.after_operand) {
+            self.state = CodeState::InNumberValue(index);
             return None;
         }
-        if character == '-' {
-            self.state = CodeState::InSignedNumberValue(index);
+        if is_identifier_start_character(character) {
+            self.state = CodeState::InIdentifier(index);
             return None;
-        }        
-        if character == '"' {
-            self.state = CodeState::InStringValue(index + 1);
+        }
+        if is_string_quote(character) {
+            self.state = CodeState::InStringValue(index + 1, character, false);
             return None;
         }
         if character == '$' {
             self.state = CodeState::InVariableValue(index + 1);
             return None;
         }
-        if character == ' ' {
-            self.state = CodeState::InWhitespace;
+        if character == COLOUR_HEX_CHAR {
+            self.state = CodeState::InColourValue(index);
+            return None;
+        }
+        if is_operator_character(character) {
+            self.state = CodeState::InOperator(index, character);
             return None;
         }
-        Some(Err(CodeTokenError::NoClosingFunctionParenthesis(index)))
+        Some(Err(CodeTokenError::UnexpectedCharacter(self.positions.point(index), character)))
     }
-    
-    fn produce_function_name_result(&mut self, start: usize, index: usize) -> CodeTokenOption {
-        let value = self.splice_input(start, index);
-        return Some(Ok(CodeTokenPropertyValue::StartFunction(value.to_string())));
+
+    fn end_parenthesis_if_possible(&mut self, index: usize) -> CodeTokenOption {
+        match self.parentheses.pop() {
+            Some(CodeParenthesis::Function) => self.produce_punctuation_result(CodeTokenPropertyValue::EndFunction, true),
+            Some(CodeParenthesis::Group) => self.produce_punctuation_result(CodeTokenPropertyValue::EndGroup, true),
+            None => Some(Err(CodeTokenError::NoOpeningFunctionParenthesis(self.positions.point(index))))
+        }
     }
 
-    fn produce_signed_number_value_result(&mut self, start: usize, index: usize) -> CodeTokenOption {
-        let raw_value = self.splice_input(start, index);
-        match raw_value.parse::<i128>() {
-            Ok(value) => return Some(Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Int(value)))),
-            Err(_) => return self.produce_float_value_result(raw_value, index)
+    fn restart_with(&mut self, result: CodeTokenResult, index: usize, character: char) -> CodeTokenOption {
+        self.state = CodeState::StartToken;
+        self.pending.push_back(result);
+        if let Some(next_result) = self.start_token_if_possible(index, character) {
+            self.pending.push_back(next_result);
         }
+        self.pending.pop_front()
+    }
+
+    fn produce_punctuation_result(&mut self, token: CodeTokenPropertyValue, after_operand: bool) -> CodeTokenOption {
+        self.after_operand = after_operand;
+        Some(Ok(token))
     }
 
-    fn produce_usize_number_value_result(&mut self, start: usize, index: usize) -> CodeTokenOption {
+    fn produce_operand_result(&mut self, value: SourceTokenPropertyValue) -> CodeTokenResult {
+        self.after_operand = true;
+        Ok(CodeTokenPropertyValue::PropertyValue(value))
+    }
+
+    fn produce_operator_result(&mut self, operator: CodeOperator) -> CodeTokenResult {
+        self.after_operand = false;
+        Ok(CodeTokenPropertyValue::Operator(operator))
+    }
+
+    fn produce_number_value_result(&mut self, start: usize, index: usize) -> CodeTokenResult {
         let raw_value = self.splice_input(start, index);
-        match raw_value.parse::<usize>() {
-            Ok(value) => return Some(Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(value)))),
-            Err(_) => return self.produce_float_value_result(raw_value, index)
+        if raw_value == "-" {
+            return self.produce_operator_result(CodeOperator::Negate);
+        }
+        if !raw_value.starts_with('-') {
+            if let Ok(value) = raw_value.parse::<usize>() {
+                return self.produce_operand_result(SourceTokenPropertyValue::USize(value));
+            }
+        } else if let Ok(value) = raw_value.parse::<i128>() {
+            return self.produce_operand_result(SourceTokenPropertyValue::Int(value));
+        }
+        match raw_value.parse::<f64>() {
+            Ok(value) => self.produce_operand_result(SourceTokenPropertyValue::Float(value)),
+            Err(_) => Err(CodeTokenError::ParseNumberError(self.positions.point(index), raw_value.to_string()))
         }
     }
 
-    fn produce_float_value_result(&mut self, raw_value: &'a str, index: usize) -> CodeTokenOption {
-        match raw_value.parse::<f64>() {
-            Ok(value) => return Some(Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Float(value)))),
-            Err(_) => return Some(Err(CodeTokenError::ParseNumberError(index, raw_value.to_string())))
+    fn produce_identifier_result(&mut self, start: usize, index: usize) -> CodeTokenResult {
+        let raw_value = self.splice_input(start, index);
+        if let Some(value) = parse_named_colour_value(raw_value) {
+            return self.produce_operand_result(SourceTokenPropertyValue::Colour(value));
+        }
+        match parse_bool_value(raw_value) {
+            Some(value) => self.produce_operand_result(SourceTokenPropertyValue::Bool(value)),
+            None => Err(CodeTokenError::UnknownIdentifier(self.positions.point(start), raw_value.to_string()))
         }
     }
 
-    fn produce_string_value_result(&mut self, start: usize, index: usize) -> CodeTokenOption {
-        let value = self.splice_input(start, index);
-        return Some(Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(value.to_string()))));
+    fn produce_string_value_result(&mut self, start: usize, index: usize) -> CodeTokenResult {
+        match unescape_string(self.splice_input(start, index)) {
+            Ok(value) => self.produce_operand_result(SourceTokenPropertyValue::String(value)),
+            Err(offset) => Err(CodeTokenError::InvalidStringEscape(self.positions.point(start + offset)))
+        }
     }
 
-    fn produce_variable_value_result(&mut self, start: usize, index: usize) -> CodeTokenOption {
+    fn produce_variable_value_result(&mut self, start: usize, index: usize) -> CodeTokenResult {
         let value = self.splice_input(start, index);
-        return Some(Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(value.to_string()))));
+        self.produce_operand_result(SourceTokenPropertyValue::Variable(value.to_string()))
     }
 
-    
-    fn handle_inside_function(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
-        if character == FUNCTION_OPENING_BRACE {
-            self.state = CodeState::StartValue;
-            return self.produce_function_name_result(start, index);
+    fn produce_colour_value_result(&mut self, start: usize, index: usize) -> CodeTokenResult {
+        let raw_value = self.splice_input(start, index);
+        match parse_colour_value(raw_value) {
+            Some(value) => self.produce_operand_result(SourceTokenPropertyValue::Colour(value)),
+            None => Err(CodeTokenError::ParseColourError(self.positions.point(start), raw_value.to_string()))
         }
-        None
     }
 
-
-    fn handle_inside_usize_number_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
-        if character == FUNCTION_CLOSING_BRACE {
-            self.state = CodeState::EndFunction;
-            return self.produce_usize_number_value_result(start, index);
+    fn handle_inside_identifier(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
+        if is_identifier_character(character) || (character == PATH_SEPARATOR_CHAR && self.is_path_separator_at(index)) {
+            return None;
         }
-        if character == ',' {
-            self.state = CodeState::EndValue;
-            return self.produce_usize_number_value_result(start, index);
+        if character == FUNCTION_OPENING_BRACE {
+            let function_name = self.splice_input(start, index).to_string();
+            self.state = CodeState::StartToken;
+            self.parentheses.push(CodeParenthesis::Function);
+            return self.produce_punctuation_result(CodeTokenPropertyValue::StartFunction(function_name), false);
         }
-        None
+        let result = self.produce_identifier_result(start, index);
+        self.restart_with(result, index, character)
     }
 
-    fn handle_inside_signed_number_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
-        if character == FUNCTION_CLOSING_BRACE {
-            self.state = CodeState::EndFunction;
-            return self.produce_signed_number_value_result(start, index);
-        }
-        if character == ',' {
-            self.state = CodeState::EndValue;
-            return self.produce_signed_number_value_result(start, index);
+    fn handle_inside_number_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
+        let negation_only = self.splice_input(start, index) == "-";
+        if character.is_numeric() || (!negation_only && (character.is_alphanumeric() || character == '.')) {
+            return None;
         }
-        None
+        let result = self.produce_number_value_result(start, index);
+        self.restart_with(result, index, character)
     }
 
-    fn handle_inside_string_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
-        if character == '"' {
-            self.state = CodeState::EndValue;
-            return self.produce_string_value_result(start, index);
+    fn handle_inside_string_value(&mut self, start: usize, quote: char, escaped: bool, index: usize, character: char) -> CodeTokenOption {
+        if character == quote && !escaped {
+            self.state = CodeState::StartToken;
+            return Some(self.produce_string_value_result(start, index));
         }
+        self.state = CodeState::InStringValue(start, quote, !escaped && character == STRING_ESCAPE_CHAR);
         None
     }
 
     fn handle_inside_variable_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
-        if character == FUNCTION_CLOSING_BRACE {
-            self.state = CodeState::EndFunction;
-            return self.produce_variable_value_result(start, index);
+        if is_variable_character(character) {
+            return None;
         }
-        if character == ',' {
-            self.state = CodeState::EndValue;
-            return self.produce_variable_value_result(start, index);
+        let result = self.produce_variable_value_result(start, index);
+        self.restart_with(result, index, character)
+    }
+
+    fn handle_inside_colour_value(&mut self, start: usize, index: usize, character: char) -> CodeTokenOption {
+        if character.is_alphanumeric() {
+            return None;
         }
-        None
+        let result = self.produce_colour_value_result(start, index);
+        self.restart_with(result, index, character)
+    }
+
+    fn handle_inside_operator(&mut self, start: usize, first: char, index: usize, character: char) -> CodeTokenOption {
+        if let Some(operator) = match_operator(&format!("{}{}", first, character)) {
+            self.state = CodeState::StartToken;
+            return Some(self.produce_operator_result(operator));
+        }
+        let result = match match_operator(&first.to_string()) {
+            Some(operator) => self.produce_operator_result(operator),
+            None => Err(CodeTokenError::UnknownOperator(self.positions.point(start), first.to_string()))
+        };
+        self.restart_with(result, index, character)
     }
 
     fn transition(&mut self, index: usize, character: char) -> CodeTokenOption {
         match self.state {
             CodeState::Start => {
                 self.start_if_possible(index, character)
-            },            
-            CodeState::StartFunction => {
-                self.start_function_if_possible(index, character)
-            },
-            CodeState::InFunction(start) => {
-                self.handle_inside_function(start, index, character)
             },
-            CodeState::StartValue => {
-                self.start_value_if_possible(index, character)
+            CodeState::StartToken => {
+                self.start_token_if_possible(index, character)
             },
-            CodeState::InSignedNumberValue(start) => {
-                self.handle_inside_signed_number_value(start, index, character)
+            CodeState::InIdentifier(start) => {
+                self.handle_inside_identifier(start, index, character)
             },
-            CodeState::InUSizeNumberValue(start) => {
-                self.handle_inside_usize_number_value(start, index, character)
+            CodeState::InNumberValue(start) => {
+                self.handle_inside_number_value(start, index, character)
             },
-            CodeState::InStringValue(start) => {
-                self.handle_inside_string_value(start, index, character)
+            CodeState::InStringValue(start, quote, escaped) => {
+                self.handle_inside_string_value(start, quote, escaped, index, character)
             },
             CodeState::InVariableValue(start) => {
                 self.handle_inside_variable_value(start, index, character)
             },
-            CodeState::EndValue => {
-                self.start_value_if_possible(index, character)
-            },
-            CodeState::InWhitespace => {
-                self.start_value_if_possible(index, character)
+            CodeState::InColourValue(start) => {
+                self.handle_inside_colour_value(start, index, character)
             },
-            CodeState::EndFunction => {
-                self.end_if_possible(index, character)
+            CodeState::InOperator(start, first) => {
+                self.handle_inside_operator(start, first, index, character)
             },
             CodeState::End => {
                 None
             }
         }
     }
+
+    fn finish(&mut self) -> CodeTokenOption {
+        if self.state == CodeState::End {
+            return None;
+        }
+        self.state = CodeState::End;
+        Some(Err(CodeTokenError::NoClosingBrace(self.positions.point(self.input.len()))))
+    }
 }
 
 impl <'a> Iterator for CodeTokenizer<'a> {
     type Item = CodeTokenResult;
     fn next(&mut self) -> CodeTokenOption {
+        if let Some(result) = self.pending.pop_front() {
+            return Some(result);
+        }
         loop {
             return match self.characters.next() {
-                Some((index, c)) => match self.transition(index, c) {
+                Some((index, c)) => match self.advance_and_transition(index, c) {
                     None => continue,
                     result => result
                 },
                 None => {
-                    None
+                    self.finish()
                 },
             }
         }
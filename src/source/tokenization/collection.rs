@@ -6,11 +6,15 @@ pub enum SpecificCollectionError {
     WrongType
 }
 
-pub fn collect_array_unsigned_shorts(from: &Vec<ArrayTokenResult>, amount: usize) -> Result<Vec::<u16>, SpecificCollectionError> {
-    let mut collected = vec!();
-    if from.len() != amount {
-        return Err(SpecificCollectionError::NotEnoughItems(from.len()));
+pub fn expect_collection_length<T>(collected: Vec<T>, amount: usize) -> Result<Vec<T>, SpecificCollectionError> {
+    if collected.len() != amount {
+        return Err(SpecificCollectionError::NotEnoughItems(collected.len()));
     }
+    Ok(collected)
+}
+
+pub fn collect_array_unsigned_shorts(from: &Vec<ArrayTokenResult>) -> Result<Vec::<u16>, SpecificCollectionError> {
+    let mut collected = vec!();
     for token in from {
         if let Ok(SourceTokenPropertyValue::USize(value)) = token {
             collected.push(*value as u16)
@@ -21,26 +25,22 @@ pub fn collect_array_unsigned_shorts(from: &Vec<ArrayTokenResult>, amount: usize
     Ok(collected)
 }
 
-pub fn collect_array_floats(from: &Vec<ArrayTokenResult>, amount: usize) -> Result<Vec::<f32>, SpecificCollectionError> {
+pub fn collect_array_sizes(from: &Vec<ArrayTokenResult>) -> Result<Vec::<(f32, SizeUnit)>, SpecificCollectionError> {
     let mut collected = vec!();
-    if from.len() != amount {
-        return Err(SpecificCollectionError::NotEnoughItems(from.len()));
-    }
     for token in from {
-        if let Ok(SourceTokenPropertyValue::Float(value)) = token {
-            collected.push(*value as f32)
-        } else {
-            return Err(SpecificCollectionError::WrongType)
+        match token {
+            Ok(SourceTokenPropertyValue::Size(value, unit)) => collected.push((*value as f32, *unit)),
+            Ok(SourceTokenPropertyValue::Float(value)) => collected.push((*value as f32, SizeUnit::Points)),
+            Ok(SourceTokenPropertyValue::USize(value)) => collected.push((*value as f32, SizeUnit::Points)),
+            Ok(SourceTokenPropertyValue::Int(value)) => collected.push((*value as f32, SizeUnit::Points)),
+            _ => return Err(SpecificCollectionError::WrongType)
         }
     }
     Ok(collected)
 }
 
-pub fn collect_array_usizes(from: &Vec<ArrayTokenResult>, amount: usize) -> Result<Vec::<usize>, SpecificCollectionError> {
+pub fn collect_array_usizes(from: &Vec<ArrayTokenResult>) -> Result<Vec::<usize>, SpecificCollectionError> {
     let mut collected = vec!();
-    if from.len() != amount {
-        return Err(SpecificCollectionError::NotEnoughItems(from.len()));
-    }
     for token in from {
         if let Ok(SourceTokenPropertyValue::USize(value)) = token {
             collected.push(*value as usize)
@@ -1,7 +1,6 @@
 use crate::prelude::*;
 
-use std::iter::Enumerate;
-use std::str::Chars;
+use std::str::CharIndices;
 
 #[derive(PartialEq, Eq, Debug)]
 enum ArrayState {
@@ -10,28 +9,64 @@ enum ArrayState {
     EndArray,
     InSignedNumberValue(usize),
     InUSizeNumberValue(usize),
-    InStringValue(usize),
+    InStringValue(usize, char, bool),
+    InBoolValue(usize),
+    InColourValue(usize),
+    InVariableValue(usize),
+    InArrayValue(usize, DelimitedValue),
+    InCodeValue(usize, DelimitedValue),
+    AfterValue,
     EndValue,
     InWhitespace
 }
 
 #[derive(PartialEq, Eq, Debug, PartialOrd, Clone)]
 pub enum ArrayTokenError {
-    NoOpeningParenthesis(usize),
-    NoClosingParenthesis(usize),
-    ParseNumberError(usize, String),
+    NoOpeningParenthesis(SourceSpan),
+    NoClosingParenthesis(SourceSpan),
+    ParseNumberError(SourceSpan, String),
+    InvalidStringEscape(SourceSpan),
+    ParseBoolError(SourceSpan, String),
+    ParseColourError(SourceSpan, String)
+}
+
+impl ArrayTokenError {
+    pub fn span(&self) -> &SourceSpan {
+        match self {
+            Self::NoOpeningParenthesis(span) |
+            Self::NoClosingParenthesis(span) |
+            Self::ParseNumberError(span, _) |
+            Self::InvalidStringEscape(span) |
+            Self::ParseBoolError(span, _) |
+            Self::ParseColourError(span, _) => span
+        }
+    }
+}
+
+impl std::fmt::Display for ArrayTokenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoOpeningParenthesis(_) => write!(f, "expected '[' to start array"),
+            Self::NoClosingParenthesis(_) => write!(f, "expected ']' to close array"),
+            Self::ParseNumberError(_, value) => write!(f, "could not parse number value {:?}", value),
+            Self::InvalidStringEscape(_) => write!(f, "invalid string escape"),
+            Self::ParseBoolError(_, value) => write!(f, "could not parse bool value {:?}", value),
+            Self::ParseColourError(_, value) => write!(f, "could not parse colour value {:?}", value)
+        }
+    }
 }
 
 pub type ArrayTokenResult = Result<SourceTokenPropertyValue, ArrayTokenError>;
 pub type ArrayTokenOption = Option<ArrayTokenResult>;
 
-pub fn tokenize_array(from: &str) -> Vec::<ArrayTokenResult> {
-    ArrayTokenizer::from_string(from).collect()
+pub fn tokenize_array(from: &str, positions: SourcePositions) -> Vec::<ArrayTokenResult> {
+    ArrayTokenizer::from_string(from).with_positions(positions).collect()
 }
 
 pub struct ArrayTokenizer<'a>{
     input: &'a str,
-    characters: Enumerate<Chars<'a>>,
+    characters: CharIndices<'a>,
+    positions: SourcePositions,
     state: ArrayState
 } 
 
@@ -39,21 +74,36 @@ impl<'a> ArrayTokenizer<'a> {
     pub fn from_string(input: &'a str) -> Self {
         Self {
             input,
-            characters: input.chars().enumerate(),
+            characters: input.char_indices(),
+            positions: SourcePositions::default(),
             state: ArrayState::Start
         }
     }
 
+    pub fn with_positions(mut self, positions: SourcePositions) -> Self {
+        self.positions = positions;
+        self
+    }
+
+    fn advance_and_transition(&mut self, index: usize, character: char) -> ArrayTokenOption {
+        self.positions.advance(index, character);
+        self.transition(index, character)
+    }
+
     fn splice_input(&mut self, from: usize, to: usize) -> &'a str {
         &self.input[from..to]
     }
 
+    fn nested_positions(&self, start: usize) -> SourcePositions {
+        SourcePositions::new(self.positions.location().clone(), self.positions.position(start))
+    }
+
     fn start_if_possible(&mut self, index: usize, character: char) -> ArrayTokenOption {
         if character == ARRAY_OPENING_CHAR {
             self.state = ArrayState::StartValue;
             return None;
         }
-        Some(Err(ArrayTokenError::NoOpeningParenthesis(index)))
+        Some(Err(ArrayTokenError::NoOpeningParenthesis(self.positions.point(index))))
     }
 
     fn start_value_if_possible(&mut self, index: usize, character: char) -> ArrayTokenOption {
@@ -69,17 +119,65 @@ impl<'a> ArrayTokenizer<'a> {
             self.state = ArrayState::InSignedNumberValue(index);
             return None;
         }        
-        if character == '"' {
-            self.state = ArrayState::InStringValue(index + 1);
+        if character.is_alphabetic() {
+            self.state = ArrayState::InBoolValue(index);
+            return None;
+        }
+        if is_string_quote(character) {
+            self.state = ArrayState::InStringValue(index + 1, character, false);
+            return None;
+        }
+        if character == '$' {
+            self.state = ArrayState::InVariableValue(index + 1);
+            return None;
+        }
+        if character == COLOUR_HEX_CHAR {
+            self.state = ArrayState::InColourValue(index);
             return None;
         }
-        if character == ' ' {
+        if character == ARRAY_OPENING_CHAR {
+            self.state = ArrayState::InArrayValue(index, DelimitedValue::default());
+            return None;
+        }
+        if character == CODE_OPENING_CHAR {
+            self.state = ArrayState::InCodeValue(index, DelimitedValue::default());
+            return None;
+        }
+        if character.is_whitespace() {
             self.state = ArrayState::InWhitespace;
             return None;
         }
-        Some(Err(ArrayTokenError::NoClosingParenthesis(index)))
+        Some(Err(ArrayTokenError::NoClosingParenthesis(self.positions.point(index))))
     }
-    
+
+    fn end_value_if_possible(&mut self, index: usize, character: char) -> ArrayTokenOption {
+        if character == ',' {
+            self.state = ArrayState::EndValue;
+            return None;
+        }
+        if character == ARRAY_CLOSING_CHAR {
+            self.state = ArrayState::EndArray;
+            return None;
+        }
+        if character.is_whitespace() {
+            return None;
+        }
+        Some(Err(ArrayTokenError::NoClosingParenthesis(self.positions.point(index))))
+    }
+
+    fn scalar_value_end_state(character: char) -> Option<ArrayState> {
+        if character == ',' {
+            return Some(ArrayState::EndValue);
+        }
+        if character == ARRAY_CLOSING_CHAR {
+            return Some(ArrayState::EndArray);
+        }
+        if character.is_whitespace() {
+            return Some(ArrayState::AfterValue);
+        }
+        None
+    }
+
     fn produce_signed_number_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
         let raw_value = self.splice_input(start, index);
         match raw_value.parse::<i128>() {
@@ -97,49 +195,129 @@ impl<'a> ArrayTokenizer<'a> {
     }
 
     fn produce_float_value_result(&mut self, raw_value: &'a str, index: usize) -> ArrayTokenOption {
+        if let Some((value, unit)) = parse_size_value(raw_value) {
+            return Some(Ok(SourceTokenPropertyValue::Size(value, unit)));
+        }
         match raw_value.parse::<f64>() {
             Ok(value) => return Some(Ok(SourceTokenPropertyValue::Float(value))),
-            Err(_) => return Some(Err(ArrayTokenError::ParseNumberError(index, raw_value.to_string())))
+            Err(_) => return Some(Err(ArrayTokenError::ParseNumberError(self.positions.point(index), raw_value.to_string())))
+        }
+    }
+
+    fn produce_bool_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
+        if let Some(value) = parse_named_colour_value(self.splice_input(start, index)) {
+            return Some(Ok(SourceTokenPropertyValue::Colour(value)));
+        }
+        match parse_bool_value(self.splice_input(start, index)) {
+            Some(value) => Some(Ok(SourceTokenPropertyValue::Bool(value))),
+            None => Some(Err(ArrayTokenError::ParseBoolError(self.positions.point(start), self.splice_input(start, index).to_string())))
+        }
+    }
+
+    fn produce_colour_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
+        match parse_colour_value(self.splice_input(start, index)) {
+            Some(value) => Some(Ok(SourceTokenPropertyValue::Colour(value))),
+            None => Some(Err(ArrayTokenError::ParseColourError(self.positions.point(start), self.splice_input(start, index).to_string())))
         }
     }
 
     fn produce_string_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
+        match unescape_string(self.splice_input(start, index)) {
+            Ok(value) => Some(Ok(SourceTokenPropertyValue::String(value))),
+            Err(offset) => Some(Err(ArrayTokenError::InvalidStringEscape(self.positions.point(start + offset))))
+        }
+    }
+
+    fn produce_variable_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
         let value = self.splice_input(start, index);
-        return Some(Ok(SourceTokenPropertyValue::String(value.to_string())));
+        Some(Ok(SourceTokenPropertyValue::Variable(value.to_string())))
+    }
+
+    fn produce_array_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
+        let array_content = self.splice_input(start, index);
+        Some(Ok(SourceTokenPropertyValue::Array(tokenize_array(array_content, self.nested_positions(start)))))
+    }
+
+    fn produce_code_value_result(&mut self, start: usize, index: usize) -> ArrayTokenOption {
+        let code_content = self.splice_input(start, index);
+        Some(Ok(SourceTokenPropertyValue::Code(tokenize_code(code_content, self.nested_positions(start)))))
     }
 
     fn handle_inside_usize_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
-        if character == ARRAY_CLOSING_CHAR {
-            self.state = ArrayState::EndArray;
-            return self.produce_usize_value_result(start, index);
-        }
-        if character == ',' {
-            self.state = ArrayState::EndValue;
+        if let Some(state) = Self::scalar_value_end_state(character) {
+            self.state = state;
             return self.produce_usize_value_result(start, index);
         }
         None
     }
 
     fn handle_inside_signed_number_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
-        if character == ARRAY_CLOSING_CHAR {
-            self.state = ArrayState::EndArray;
+        if let Some(state) = Self::scalar_value_end_state(character) {
+            self.state = state;
             return self.produce_signed_number_value_result(start, index);
         }
-        if character == ',' {
-            self.state = ArrayState::EndValue;
-            return self.produce_signed_number_value_result(start, index);
+        None
+    }
+
+    fn handle_inside_bool_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
+        if let Some(state) = Self::scalar_value_end_state(character) {
+            self.state = state;
+            return self.produce_bool_value_result(start, index);
         }
         None
     }
 
-    fn handle_inside_string_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
-        if character == '"' {
-            self.state = ArrayState::EndValue;
+    fn handle_inside_colour_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
+        if let Some(state) = Self::scalar_value_end_state(character) {
+            self.state = state;
+            return self.produce_colour_value_result(start, index);
+        }
+        None
+    }
+
+    fn handle_inside_variable_value(&mut self, start: usize, index: usize, character: char) -> ArrayTokenOption {
+        if let Some(state) = Self::scalar_value_end_state(character) {
+            self.state = state;
+            return self.produce_variable_value_result(start, index);
+        }
+        None
+    }
+
+    fn handle_inside_string_value(&mut self, start: usize, quote: char, escaped: bool, index: usize, character: char) -> ArrayTokenOption {
+        if character == quote && !escaped {
+            self.state = ArrayState::AfterValue;
             return self.produce_string_value_result(start, index);
         }
+        self.state = ArrayState::InStringValue(start, quote, !escaped && character == STRING_ESCAPE_CHAR);
         None
     }
 
+    fn handle_inside_array_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char) -> ArrayTokenOption {
+        match delimited.advance(ARRAY_OPENING_CHAR, ARRAY_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = ArrayState::InArrayValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = ArrayState::AfterValue;
+                self.produce_array_value_result(start, index + 1)
+            }
+        }
+    }
+
+    fn handle_inside_code_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char) -> ArrayTokenOption {
+        match delimited.advance(CODE_OPENING_CHAR, CODE_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = ArrayState::InCodeValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = ArrayState::AfterValue;
+                self.produce_code_value_result(start, index + 1)
+            }
+        }
+    }
+
     fn transition(&mut self, index: usize, character: char) -> ArrayTokenOption {
         match self.state {
             ArrayState::Start => {
@@ -154,8 +332,26 @@ impl<'a> ArrayTokenizer<'a> {
             ArrayState::InUSizeNumberValue(start) => {
                 self.handle_inside_usize_value(start, index, character)
             },
-            ArrayState::InStringValue(start) => {
-                self.handle_inside_string_value(start, index, character)
+            ArrayState::InStringValue(start, quote, escaped) => {
+                self.handle_inside_string_value(start, quote, escaped, index, character)
+            },
+            ArrayState::InBoolValue(start) => {
+                self.handle_inside_bool_value(start, index, character)
+            },
+            ArrayState::InColourValue(start) => {
+                self.handle_inside_colour_value(start, index, character)
+            },
+            ArrayState::InVariableValue(start) => {
+                self.handle_inside_variable_value(start, index, character)
+            },
+            ArrayState::InArrayValue(start, delimited) => {
+                self.handle_inside_array_value(start, delimited, index, character)
+            },
+            ArrayState::InCodeValue(start, delimited) => {
+                self.handle_inside_code_value(start, delimited, index, character)
+            },
+            ArrayState::AfterValue => {
+                self.end_value_if_possible(index, character)
             },
             ArrayState::EndValue => {
                 self.start_value_if_possible(index, character)
@@ -175,7 +371,7 @@ impl <'a> Iterator for ArrayTokenizer<'a> {
     fn next(&mut self) -> ArrayTokenOption {
         loop {
             return match self.characters.next() {
-                Some((index, c)) => match self.transition(index, c) {
+                Some((index, c)) => match self.advance_and_transition(index, c) {
                     None => continue,
                     result => result
                 },
@@ -0,0 +1,49 @@
+pub const COLOUR_HEX_CHAR: char = '#';
+
+const NAMED_COLOURS: [(&str, [u8; 4]); 20] = [
+    ("transparent", [0, 0, 0, 0]),
+    ("black", [0, 0, 0, 255]),
+    ("white", [255, 255, 255, 255]),
+    ("red", [255, 0, 0, 255]),
+    ("green", [0, 128, 0, 255]),
+    ("lime", [0, 255, 0, 255]),
+    ("blue", [0, 0, 255, 255]),
+    ("yellow", [255, 255, 0, 255]),
+    ("cyan", [0, 255, 255, 255]),
+    ("magenta", [255, 0, 255, 255]),
+    ("gray", [128, 128, 128, 255]),
+    ("grey", [128, 128, 128, 255]),
+    ("silver", [192, 192, 192, 255]),
+    ("maroon", [128, 0, 0, 255]),
+    ("olive", [128, 128, 0, 255]),
+    ("navy", [0, 0, 128, 255]),
+    ("purple", [128, 0, 128, 255]),
+    ("teal", [0, 128, 128, 255]),
+    ("orange", [255, 165, 0, 255]),
+    ("pink", [255, 192, 203, 255])
+];
+
+pub fn parse_colour_value(raw_value: &str) -> Option<[u8; 4]> {
+    match raw_value.strip_prefix(COLOUR_HEX_CHAR) {
+        Some(hex_value) => parse_hex_colour_value(hex_value),
+        None => parse_named_colour_value(raw_value)
+    }
+}
+
+pub fn parse_named_colour_value(raw_value: &str) -> Option<[u8; 4]> {
+    NAMED_COLOURS.iter()
+        .find(|(name, _)| *name == raw_value)
+        .map(|(_, colour)| *colour)
+}
+
+fn parse_hex_colour_value(hex_value: &str) -> Option<[u8; 4]> {
+    if !hex_value.chars().all(|character| character.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |index: usize| u8::from_str_radix(&hex_value[index..index + 2], 16).ok();
+    match hex_value.len() {
+        6 => Some([component(0)?, component(2)?, component(4)?, 255]),
+        8 => Some([component(0)?, component(2)?, component(4)?, component(6)?]),
+        _ => None
+    }
+}
\ No newline at end of file
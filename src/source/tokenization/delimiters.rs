@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct DelimitedValue {
+    depth: usize,
+    quote: Option<char>,
+    escaped: bool
+}
+
+impl DelimitedValue {
+    pub fn advance(self, opening: char, closing: char, character: char) -> Option<Self> {
+        if self.quote.is_none() {
+            if character == closing && self.depth == 0 {
+                return None;
+            }
+            if character == closing {
+                return Some(Self { depth: self.depth - 1, ..self });
+            }
+            if character == opening {
+                return Some(Self { depth: self.depth + 1, ..self });
+            }
+        }
+        let (quote, escaped) = track_string_quote(self.quote, self.escaped, character);
+        Some(Self { quote, escaped, ..self })
+    }
+}
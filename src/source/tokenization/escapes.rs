@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+pub const STRING_QUOTE_CHARS: [char; 2] = ['"', '\''];
+pub const STRING_ESCAPE_CHAR: char = '\\';
+
+pub fn is_string_quote(character: char) -> bool {
+    STRING_QUOTE_CHARS.contains(&character)
+}
+
+pub fn unescape_string(raw: &str) -> Result<String, usize> {
+    let mut value = String::new();
+    let mut characters = raw.char_indices();
+    while let Some((index, character)) = characters.next() {
+        if character != STRING_ESCAPE_CHAR {
+            value.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some((_, '"')) => value.push('"'),
+            Some((_, '\'')) => value.push('\''),
+            Some((_, '\\')) => value.push('\\'),
+            Some((_, 'n')) => value.push('\n'),
+            Some((_, 't')) => value.push('\t'),
+            Some((_, 'u')) => value.push(unescape_unicode(&mut characters).ok_or(index)?),
+            _ => return Err(index)
+        }
+    }
+    Ok(value)
+}
+
+fn unescape_unicode(characters: &mut CharIndices) -> Option<char> {
+    if characters.next()?.1 != '{' {
+        return None;
+    }
+    let mut code = String::new();
+    loop {
+        match characters.next()? {
+            (_, '}') => break,
+            (_, digit) if digit.is_ascii_hexdigit() && code.len() < 6 => code.push(digit),
+            _ => return None
+        }
+    }
+    char::from_u32(u32::from_str_radix(&code, 16).ok()?)
+}
+
+pub fn track_string_quote(quote: Option<char>, escaped: bool, character: char) -> (Option<char>, bool) {
+    match quote {
+        Some(quote) if escaped => (Some(quote), false),
+        Some(quote) if character == STRING_ESCAPE_CHAR => (Some(quote), true),
+        Some(quote) if character == quote => (None, false),
+        Some(quote) => (Some(quote), false),
+        None if is_string_quote(character) => (Some(character), false),
+        None => (None, false)
+    }
+}
@@ -3,6 +3,11 @@ mod collections;
 mod collection;
 mod code;
 mod lookup;
+mod escapes;
+mod delimiters;
+mod colours;
+mod units;
+mod syntax;
 mod tests;
 
 pub use source::*;
@@ -10,6 +15,13 @@ pub use collections::*;
 pub use collection::*;
 pub use code::*;
 pub use lookup::*;
+pub use escapes::*;
+pub use delimiters::*;
+pub use colours::*;
+pub use units::*;
+pub use syntax::*;
+
+use crate::prelude::*;
 
 pub const FUNCTION_OPENING_BRACE: char = '(';
 pub const FUNCTION_CLOSING_BRACE: char = ')';
@@ -17,43 +29,120 @@ pub const ARRAY_OPENING_CHAR: char = '[';
 pub const ARRAY_CLOSING_CHAR: char = ']';
 pub const CODE_OPENING_CHAR: char = '{';
 pub const CODE_CLOSING_CHAR: char = '}';
+pub const PATH_SEPARATOR: &str = "::";
+pub const PATH_SEPARATOR_CHAR: char = ':';
 
-#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum SourceTokenError {
-    CouldNotFindStartTag(usize),
-    CouldNotParseNumberValue(usize),
-    CouldNotFindControlName(usize),
-    CouldNotFindPropertyStartSymbol(usize),
-    CouldNotFindControlToClose(usize),
-    CouldNotFindControlCloseSymbol(usize),
-    ClosingWrongTag(usize)
+    CouldNotFindStartTag(SourceSpan),
+    CouldNotParseNumberValue(SourceSpan),
+    CouldNotFindControlName(SourceSpan),
+    CouldNotFindPropertyStartSymbol(SourceSpan),
+    CouldNotFindControlToClose(SourceSpan),
+    CouldNotFindControlCloseSymbol(SourceSpan),
+    ClosingWrongTag(SourceSpan),
+    CouldNotFindCommentStart(SourceSpan),
+    UnterminatedComment(SourceSpan),
+    InvalidStringEscape(SourceSpan),
+    CouldNotParseIdentifierValue(SourceSpan),
+    CouldNotParseColourValue(SourceSpan),
+    UnclosedControl(SourceSpan)
+}
+
+impl SourceTokenError {
+    pub fn span(&self) -> &SourceSpan {
+        match self {
+            Self::CouldNotFindStartTag(span) |
+            Self::CouldNotParseNumberValue(span) |
+            Self::CouldNotFindControlName(span) |
+            Self::CouldNotFindPropertyStartSymbol(span) |
+            Self::CouldNotFindControlToClose(span) |
+            Self::CouldNotFindControlCloseSymbol(span) |
+            Self::ClosingWrongTag(span) |
+            Self::CouldNotFindCommentStart(span) |
+            Self::UnterminatedComment(span) |
+            Self::InvalidStringEscape(span) |
+            Self::CouldNotParseIdentifierValue(span) |
+            Self::CouldNotParseColourValue(span) |
+            Self::UnclosedControl(span) => span
+        }
+    }
+}
+
+impl std::fmt::Display for SourceTokenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CouldNotFindStartTag(_) => write!(f, "expected '<' to start a control"),
+            Self::CouldNotParseNumberValue(_) => write!(f, "could not parse number value"),
+            Self::CouldNotFindControlName(_) => write!(f, "could not find control name"),
+            Self::CouldNotFindPropertyStartSymbol(_) => write!(f, "could not find property value"),
+            Self::CouldNotFindControlToClose(_) => write!(f, "closing tag has no open control"),
+            Self::CouldNotFindControlCloseSymbol(_) => write!(f, "expected '>' to close the control"),
+            Self::ClosingWrongTag(_) => write!(f, "closing tag does not match an open control"),
+            Self::CouldNotFindCommentStart(_) => write!(f, "could not find comment start"),
+            Self::UnterminatedComment(_) => write!(f, "comment is never closed"),
+            Self::InvalidStringEscape(_) => write!(f, "invalid string escape"),
+            Self::CouldNotParseIdentifierValue(_) => write!(f, "could not parse bool or colour value"),
+            Self::CouldNotParseColourValue(_) => write!(f, "could not parse colour value"),
+            Self::UnclosedControl(_) => write!(f, "control is never closed")
+        }
+    }
 }
 
 #[derive(PartialEq, PartialOrd, Debug, Clone)]
 pub enum SourceTokenPropertyValue {
     String(String),
+    Bool(bool),
     Int(i128),
     USize(usize),
     Float(f64), 
     Array(Vec<ArrayTokenResult>), 
     Code(Vec<CodeTokenResult>),
-    Variable(String)
+    Variable(String),
+    Colour([u8; 4]),
+    Size(f64, SizeUnit)
 }
 
 
-#[derive(PartialEq, PartialOrd, Debug)]
+#[derive(PartialEq, PartialOrd, Debug, Clone)]
 pub enum SourceTokenPropertyType {
     Standard,
     Variable
 }
 
-#[derive(PartialEq, PartialOrd, Debug)]
+#[derive(PartialEq, PartialOrd, Debug, Clone)]
 pub enum SourceToken {
     Control(String),
     EndControl(String),
     Property(SourceTokenPropertyType, String),
-    PropertyValue(SourceTokenPropertyValue)
+    PropertyValue(SourceTokenPropertyValue),
+    Text(String)
+}
+
+#[derive(PartialEq, PartialOrd, Debug)]
+pub struct SpannedSourceToken(SourceToken, SourceSpan);
+
+impl SpannedSourceToken {
+    pub fn new(token: SourceToken, span: SourceSpan) -> Self {
+        Self(token, span)
+    }
+
+    pub fn token(&self) -> &SourceToken {
+        &self.0
+    }
+
+    pub fn span(&self) -> &SourceSpan {
+        &self.1
+    }
+}
+
+pub fn parse_bool_value(raw_value: &str) -> Option<bool> {
+    match raw_value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None
+    }
 }
 
-pub type SourceTokenResult = Result<SourceToken, SourceTokenError>;
+pub type SourceTokenResult = Result<SpannedSourceToken, SourceTokenError>;
 pub type SourceTokenOption = Option<SourceTokenResult>;
\ No newline at end of file
@@ -1,6 +1,16 @@
 
 use crate::prelude::*;
 
+use std::collections::VecDeque;
+
+const COMMENT_OPENING: &str = "<!--";
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum SourceCommentContext {
+    BetweenControls,
+    BetweenProperties
+}
+
 #[derive(PartialEq, Eq, Debug)]
 enum SourceState {
     Start,
@@ -9,20 +19,36 @@ enum SourceState {
     EndControl,
     EndNestedControl(usize),
     InProperty(usize),
-    InStringPropertyValue(usize),
+    InStringPropertyValue(usize, char, bool),
     InVariablePropertyValue(usize),
     InUSizeNumberPropertyValue(usize),
     InSignedNumberPropertyValue(usize),
-    InArrayPropertyValue(usize),
-    InCodePropertyValue(usize),
+    InIdentifierPropertyValue(usize),
+    InColourPropertyValue(usize),
+    InArrayPropertyValue(usize, DelimitedValue),
+    InCodePropertyValue(usize, DelimitedValue),
     StartPropertyValue,
-    InWhitespace
-} 
+    InWhitespace,
+    InText(usize),
+    StartComment(usize, usize, SourceCommentContext),
+    InComment(usize, usize, SourceCommentContext),
+    Recovering(SourceRecovery)
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct SourceRecovery {
+    control_opened: bool,
+    self_closing: bool,
+    quote: Option<char>,
+    escaped: bool
+}
 
 pub struct SourceTokenizer<'a> {
     input: &'a str,
-    characters: Enumerate<Chars<'a>>,
-    current_parent: Vec<&'a str>,
+    characters: CharIndices<'a>,
+    positions: SourcePositions,
+    current_parent: Vec<(&'a str, usize)>,
+    pending: VecDeque<SourceTokenResult>,
     state: SourceState
 }
 
@@ -30,13 +56,17 @@ impl <'a> Iterator for SourceTokenizer<'a> {
     type Item = SourceTokenResult;
     fn next(&mut self) -> SourceTokenOption {
         loop {
+            if let Some(result) = self.pending.pop_front() {
+                return Some(result);
+            }
             return match self.characters.next() {
-                Some((index, c)) => match self.transition(index, c) {
+                Some((index, c)) => match self.advance_and_transition(index, c) {
                     None => continue,
                     result => result
                 },
-                None => {
-                    None
+                None => match self.finish() {
+                    None if !self.pending.is_empty() => continue,
+                    result => result
                 },
             }
         }
@@ -47,12 +77,24 @@ impl<'a> SourceTokenizer<'a> {
     pub fn from_string(input: &'a str) -> Self {
         Self {
             input,
-            characters: input.chars().enumerate(),
+            characters: input.char_indices(),
+            positions: SourcePositions::default(),
             state: SourceState::Start,
-            current_parent: vec![]
+            current_parent: vec![],
+            pending: VecDeque::new()
         }
     }
 
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.positions = SourcePositions::new(location, SourcePosition::default());
+        self
+    }
+
+    fn advance_and_transition(&mut self, index: usize, character: char) -> SourceTokenOption {
+        self.positions.advance(index, character);
+        self.transition(index, character)
+    }
+
     fn transition(&mut self, index: usize, character: char) -> SourceTokenOption {
         match self.state {
             SourceState::Start => {
@@ -76,8 +118,8 @@ impl<'a> SourceTokenizer<'a> {
             SourceState::StartPropertyValue => {
                 self.start_property_value_if_possible(index, character)
             },
-            SourceState::InStringPropertyValue(start) => {
-                self.handle_inside_string_property_value(start, index, character)
+            SourceState::InStringPropertyValue(start, quote, escaped) => {
+                self.handle_inside_string_property_value(start, quote, escaped, index, character)
             },
             SourceState::InVariablePropertyValue(start) => {
                 self.handle_inside_variable_property_value(start, index, character)
@@ -88,22 +130,110 @@ impl<'a> SourceTokenizer<'a> {
             SourceState::InSignedNumberPropertyValue(start) => {
                 self.handle_inside_signed_number_property_value(start, index, character)
             },
-            SourceState::InArrayPropertyValue(start) => {
-                self.handle_inside_array_property_value(start, index, character)
+            SourceState::InIdentifierPropertyValue(start) => {
+                self.handle_inside_identifier_property_value(start, index, character)
+            },
+            SourceState::InColourPropertyValue(start) => {
+                self.handle_inside_colour_property_value(start, index, character)
             },
-            SourceState::InCodePropertyValue(start) => {
-                self.handle_inside_code_property_value(start, index, character)
+            SourceState::InArrayPropertyValue(start, delimited) => {
+                self.handle_inside_array_property_value(start, delimited, index, character)
+            },
+            SourceState::InCodePropertyValue(start, delimited) => {
+                self.handle_inside_code_property_value(start, delimited, index, character)
             },
             SourceState::InWhitespace => {
                 self.handle_inside_whitespace(index, character)
+            },
+            SourceState::InText(start) => {
+                self.handle_inside_text(start, index, character)
+            },
+            SourceState::StartComment(start, matched, context) => {
+                self.start_comment_if_possible(start, matched, context, index, character)
+            },
+            SourceState::InComment(start, dashes, context) => {
+                self.handle_inside_comment(start, dashes, context, character)
+            },
+            SourceState::Recovering(recovery) => {
+                self.recover_if_possible(recovery, index, character)
+            }
+        }
+    }
+
+    fn finish(&mut self) -> SourceTokenOption {
+        let result = match self.state {
+            SourceState::StartComment(start, _, _) | SourceState::InComment(start, _, _) => {
+                Some(Err(SourceTokenError::UnterminatedComment(self.positions.point(start))))
+            },
+            SourceState::InText(start) => {
+                self.produce_text_result(start, self.input.len())
+            },
+            _ => None
+        };
+        self.state = SourceState::Start;
+        while let Some((control_name, start)) = self.current_parent.pop() {
+            self.close_unclosed_control(control_name, start);
+        }
+        result
+    }
+
+    fn close_unclosed_control(&mut self, control_name: &str, start: usize) {
+        let span = self.positions.point(start);
+        self.pending.push_back(Err(SourceTokenError::UnclosedControl(span.clone())));
+        self.pending.push_back(Ok(SpannedSourceToken::new(SourceToken::EndControl(String::from(control_name)), span)));
+    }
+
+    fn produce_error_and_recover(&mut self, error: SourceTokenError, control_opened: bool) -> SourceTokenOption {
+        self.state = SourceState::Recovering(SourceRecovery {
+            control_opened,
+            self_closing: false,
+            quote: None,
+            escaped: false
+        });
+        Some(Err(error))
+    }
+
+    fn recover_if_possible(&mut self, recovery: SourceRecovery, index: usize, character: char) -> SourceTokenOption {
+        if recovery.quote.is_none() && character == '<' {
+            self.state = SourceState::StartControl;
+            return None;
+        }
+        if recovery.quote.is_none() && character == '>' {
+            self.state = SourceState::Start;
+            if recovery.control_opened && recovery.self_closing {
+                if let Some((control_name, _)) = self.current_parent.pop() {
+                    return self.produce_token(SourceToken::EndControl(String::from(control_name)), index, index + 1);
+                }
             }
+            return None;
         }
+        let (quote, escaped) = track_string_quote(recovery.quote, recovery.escaped, character);
+        let self_closing = match character {
+            '/' => true,
+            character if character.is_whitespace() => recovery.self_closing,
+            _ => false
+        };
+        self.state = SourceState::Recovering(SourceRecovery {
+            self_closing,
+            quote,
+            escaped,
+            ..recovery
+        });
+        None
     }
 
     fn splice_input(&mut self, from: usize, to: usize) -> &'a str {
         &self.input[from..to]
     }
 
+    fn produce_token(&self, token: SourceToken, start: usize, end: usize) -> SourceTokenOption {
+        Some(Ok(SpannedSourceToken::new(token, self.positions.span(start, end))))
+    }
+
+    fn nested_positions(&self, start: usize) -> SourcePositions {
+        SourcePositions::new(self.positions.location().clone(), self.positions.position(start))
+    }
+
     fn start_if_possible(&mut self, index: usize, character: char)  -> SourceTokenOption {
         if character == '<' {
             self.state = SourceState::StartControl;
@@ -112,10 +242,31 @@ impl<'a> SourceTokenizer<'a> {
         if character.is_whitespace() {
             return None;
         }
-        Some(Err(SourceTokenError::CouldNotFindStartTag(index)))
+        if !self.current_parent.is_empty() {
+            self.state = SourceState::InText(index);
+            return None;
+        }
+        self.produce_error_and_recover(SourceTokenError::CouldNotFindStartTag(self.positions.point(index)), false)
+    }
+
+    fn produce_text_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
+        let text = self.splice_input(start, index).split_whitespace().collect::<Vec<&str>>().join(" ");
+        self.produce_token(SourceToken::Text(text), start, index)
+    }
+
+    fn handle_inside_text(&mut self, start: usize, index: usize, character: char) -> SourceTokenOption {
+        if character == '<' {
+            self.state = SourceState::StartControl;
+            return self.produce_text_result(start, index);
+        }
+        None
     }
     
     fn start_control_if_possible(&mut self, index: usize, character: char)  -> SourceTokenOption {
+        if character == '!' {
+            self.state = SourceState::StartComment(index - 1, 2, SourceCommentContext::BetweenControls);
+            return None;
+        }
         if character == '/' {
             self.state = SourceState::EndNestedControl(index + 1);
             return None;
@@ -124,13 +275,13 @@ impl<'a> SourceTokenizer<'a> {
             self.state = SourceState::InControl(index);
             return None;
         }
-        Some(Err(SourceTokenError::CouldNotFindControlName(index)))
+        self.produce_error_and_recover(SourceTokenError::CouldNotFindControlName(self.positions.point(index)), false)
     }
 
     fn produce_control_result(&mut self, start: usize, index: usize)  -> SourceTokenOption {
         let control_name = self.splice_input(start, index);
-        self.current_parent.push(control_name);
-        Some(Ok(SourceToken::Control(String::from(control_name))))
+        self.current_parent.push((control_name, start));
+        self.produce_token(SourceToken::Control(String::from(control_name)), start, index)
     }
 
     fn handle_inside_control(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
@@ -159,7 +310,7 @@ impl<'a> SourceTokenizer<'a> {
                 token_type = SourceTokenPropertyType::Variable;
             }
         }
-        Some(Ok(SourceToken::Property(token_type, String::from(input))))
+        self.produce_token(SourceToken::Property(token_type, String::from(input)), start, index)
     }
 
     fn handle_inside_property(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
@@ -183,8 +334,8 @@ impl<'a> SourceTokenizer<'a> {
     }
     
     fn start_property_value_if_possible(&mut self, index: usize, character: char)  -> SourceTokenOption {
-        if character == '"' {
-            self.state = SourceState::InStringPropertyValue(index + 1);
+        if is_string_quote(character) {
+            self.state = SourceState::InStringPropertyValue(index + 1, character, false);
             return None;
         }
         if character == '$' {
@@ -199,63 +350,99 @@ impl<'a> SourceTokenizer<'a> {
             self.state = SourceState::InSignedNumberPropertyValue(index);
             return None;
         }
+        if character.is_alphabetic() {
+            self.state = SourceState::InIdentifierPropertyValue(index);
+            return None;
+        }
+        if character == COLOUR_HEX_CHAR {
+            self.state = SourceState::InColourPropertyValue(index);
+            return None;
+        }
         if character == ARRAY_OPENING_CHAR {
-            self.state = SourceState::InArrayPropertyValue(index);
+            self.state = SourceState::InArrayPropertyValue(index, DelimitedValue::default());
             return None;
         }
         if character == CODE_OPENING_CHAR {
-            self.state = SourceState::InCodePropertyValue(index);
+            self.state = SourceState::InCodePropertyValue(index, DelimitedValue::default());
             return None;
         }
-        Some(Err(SourceTokenError::CouldNotFindPropertyStartSymbol(index)))
+        self.produce_error_and_recover(SourceTokenError::CouldNotFindPropertyStartSymbol(self.positions.point(index)), true)
     }  
 
     fn produce_string_property_value_result(&mut self, start: usize, index: usize)  -> SourceTokenOption {
-        Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from(self.splice_input(start, index))))))
+        match unescape_string(self.splice_input(start, index)) {
+            Ok(value) => self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::String(value)), start, index),
+            Err(offset) => Some(Err(SourceTokenError::InvalidStringEscape(self.positions.point(start + offset))))
+        }
     }
 
     fn produce_variable_property_value_result(&mut self, start: usize, index: usize)  -> SourceTokenOption {
-        Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from(self.splice_input(start, index))))))
+        let value = self.splice_input(start, index);
+        self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from(value))), start, index)
     }
 
     fn produce_usize_number_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
         let raw_value = self.splice_input(start, index);
         match raw_value.parse::<usize>() {
-            Ok(value) => return Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::USize(value)))),
-            Err(_) => return self.produce_float_property_value_result(raw_value, index)
+            Ok(value) => return self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::USize(value)), start, index),
+            Err(_) => return self.produce_float_property_value_result(raw_value, start, index)
         }
     }
 
     fn produce_signed_number_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
         let raw_value = self.splice_input(start, index);
         match raw_value.parse::<i128>() {
-            Ok(value) => return Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Int(value)))),
-            Err(_) => return self.produce_float_property_value_result(raw_value, index)
+            Ok(value) => return self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Int(value)), start, index),
+            Err(_) => return self.produce_float_property_value_result(raw_value, start, index)
         }
     }
 
-    fn produce_float_property_value_result(&mut self, raw_value: &'a str, index: usize) -> SourceTokenOption {
+    fn produce_float_property_value_result(&mut self, raw_value: &'a str, start: usize, index: usize) -> SourceTokenOption {
+        if let Some((value, unit)) = parse_size_value(raw_value) {
+            return self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Size(value, unit)), start, index);
+        }
         match raw_value.parse::<f64>() {
-            Ok(value) => return Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Float(value)))),
-            Err(_) => return Some(Err(SourceTokenError::CouldNotParseNumberValue(index)))
+            Ok(value) => return self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Float(value)), start, index),
+            Err(_) => return Some(Err(SourceTokenError::CouldNotParseNumberValue(self.positions.point(index))))
+        }
+    }
+
+    fn produce_identifier_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
+        let raw_value = self.splice_input(start, index);
+        if let Some(value) = parse_named_colour_value(raw_value) {
+            return self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour(value)), start, index);
+        }
+        match parse_bool_value(raw_value) {
+            Some(value) => self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Bool(value)), start, index),
+            None => Some(Err(SourceTokenError::CouldNotParseIdentifierValue(self.positions.point(start))))
+        }
+    }
+
+    fn produce_colour_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
+        match parse_colour_value(self.splice_input(start, index)) {
+            Some(value) => self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour(value)), start, index),
+            None => Some(Err(SourceTokenError::CouldNotParseColourValue(self.positions.point(start))))
         }
     }
 
     fn produce_array_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
         let code_content = self.splice_input(start, index);
-        Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Array(tokenize_array(code_content)))))
+        let array = tokenize_array(code_content, self.nested_positions(start));
+        self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Array(array)), start, index)
     }
 
     fn produce_code_property_value_result(&mut self, start: usize, index: usize) -> SourceTokenOption {
         let code_content = self.splice_input(start, index);
-        Some(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Code(tokenize_code(code_content)))))
+        let code = tokenize_code(code_content, self.nested_positions(start));
+        self.produce_token(SourceToken::PropertyValue(SourceTokenPropertyValue::Code(code)), start, index)
     }
     
-    fn handle_inside_string_property_value(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
-        if character == '"' {
+    fn handle_inside_string_property_value(&mut self, start: usize, quote: char, escaped: bool, index: usize, character: char)  -> SourceTokenOption {
+        if character == quote && !escaped {
             self.state = SourceState::InWhitespace;
             return self.produce_string_property_value_result(start, index);
         }
+        self.state = SourceState::InStringPropertyValue(start, quote, !escaped && character == STRING_ESCAPE_CHAR);
         None
     }
 
@@ -295,48 +482,102 @@ impl<'a> SourceTokenizer<'a> {
         None
     }
 
-    fn handle_inside_array_property_value(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
-        if character == ARRAY_CLOSING_CHAR {
+    fn handle_inside_identifier_property_value(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
+        if character.is_whitespace() {
             self.state = SourceState::InWhitespace;
-            return self.produce_array_property_value_result(start, index + 1);
+            return self.produce_identifier_property_value_result(start, index);
+        }
+        if character == '/' {
+            self.state = SourceState::EndControl;
+            return self.produce_identifier_property_value_result(start, index);
+        }
+        if character == '>' {
+            self.state = SourceState::Start;
+            return self.produce_identifier_property_value_result(start, index);
         }
         None
     }
 
-    fn handle_inside_code_property_value(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
-        if character == CODE_CLOSING_CHAR {
+    fn handle_inside_colour_property_value(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
+        if character.is_whitespace() {
             self.state = SourceState::InWhitespace;
-            return self.produce_code_property_value_result(start, index + 1);
+            return self.produce_colour_property_value_result(start, index);
+        }
+        if character == '/' {
+            self.state = SourceState::EndControl;
+            return self.produce_colour_property_value_result(start, index);
+        }
+        if character == '>' {
+            self.state = SourceState::Start;
+            return self.produce_colour_property_value_result(start, index);
         }
         None
     }
 
+    fn handle_inside_array_property_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char)  -> SourceTokenOption {
+        match delimited.advance(ARRAY_OPENING_CHAR, ARRAY_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = SourceState::InArrayPropertyValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = SourceState::InWhitespace;
+                self.produce_array_property_value_result(start, index + 1)
+            }
+        }
+    }
+
+    fn handle_inside_code_property_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char)  -> SourceTokenOption {
+        match delimited.advance(CODE_OPENING_CHAR, CODE_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = SourceState::InCodePropertyValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = SourceState::InWhitespace;
+                self.produce_code_property_value_result(start, index + 1)
+            }
+        }
+    }
+
     fn end_control_if_possible(&mut self, index: usize, character: char)  -> SourceTokenOption {
         if character == '>' {
             self.state = SourceState::Start;
             match self.current_parent.pop() {
-                Some(control_name) => return Some(Ok(SourceToken::EndControl(String::from(control_name)))),
-                None => return Some(Err(SourceTokenError::CouldNotFindControlToClose(index)))
+                Some((control_name, _)) => return self.produce_token(SourceToken::EndControl(String::from(control_name)), index, index + 1),
+                None => return Some(Err(SourceTokenError::CouldNotFindControlToClose(self.positions.point(index))))
             };
         }
-        Some(Err(SourceTokenError::CouldNotFindControlCloseSymbol(index)))
+        self.produce_error_and_recover(SourceTokenError::CouldNotFindControlCloseSymbol(self.positions.point(index)), true)
     }
 
     fn end_nested_control_if_possible(&mut self, start: usize, index: usize, character: char)  -> SourceTokenOption {
-        if character == '>' {
-            self.state = SourceState::Start;
-            match self.current_parent.pop() {
-                Some(control_name) => {
-                    let closing_control_name = self.splice_input(start, index);
-                    if closing_control_name == control_name {
-                        return Some(Ok(SourceToken::EndControl(String::from(control_name))))
-                    }
-                    return Some(Err(SourceTokenError::ClosingWrongTag(index)))
-                },
-                None => return Some(Err(SourceTokenError::CouldNotFindControlToClose(index)))
-            };
+        if character != '>' {
+            return None;
+        }
+        self.state = SourceState::Start;
+        if self.current_parent.is_empty() {
+            return Some(Err(SourceTokenError::CouldNotFindControlToClose(self.positions.point(index))));
+        }
+        let closing_control_name = self.splice_input(start, index);
+        match self.current_parent.iter().rposition(|(control_name, _)| *control_name == closing_control_name) {
+            Some(position) => {
+                while self.current_parent.len() > position + 1 {
+                    let (control_name, control_start) = self.current_parent.pop().unwrap();
+                    self.close_unclosed_control(control_name, control_start);
+                }
+                let (control_name, _) = self.current_parent.pop().unwrap();
+                let result = self.produce_token(SourceToken::EndControl(String::from(control_name)), start, index);
+                match self.pending.pop_front() {
+                    Some(pending_result) => {
+                        self.pending.extend(result);
+                        Some(pending_result)
+                    },
+                    None => result
+                }
+            },
+            None => Some(Err(SourceTokenError::ClosingWrongTag(self.positions.point(index))))
         }
-        None
     }
 
     fn handle_inside_whitespace(&mut self, index: usize, character: char)  -> SourceTokenOption {
@@ -348,11 +589,56 @@ impl<'a> SourceTokenizer<'a> {
             self.state = SourceState::Start;
             return None;
         }
+        if character == '<' {
+            self.state = SourceState::StartComment(index, 1, SourceCommentContext::BetweenProperties);
+            return None;
+        }
         if character.is_whitespace() {
             return None;
         }
                   
         self.state = SourceState::InProperty(index);
         None
-    }    
+    }
+
+    fn start_comment_if_possible(&mut self, start: usize, matched: usize, context: SourceCommentContext, index: usize, character: char) -> SourceTokenOption {
+        if matched == 1 && context == SourceCommentContext::BetweenProperties && character != '!' {
+            if let Some((control_name, _)) = self.current_parent.pop() {
+                self.pending.extend(self.produce_token(SourceToken::EndControl(String::from(control_name)), start, start));
+            }
+            self.state = SourceState::StartControl;
+            if let Some(result) = self.start_control_if_possible(index, character) {
+                self.pending.push_back(result);
+            }
+            return Some(Err(SourceTokenError::CouldNotFindControlCloseSymbol(self.positions.point(start))));
+        }
+        if COMMENT_OPENING.chars().nth(matched) != Some(character) {
+            return self.produce_error_and_recover(
+                SourceTokenError::CouldNotFindCommentStart(self.positions.point(index)),
+                context == SourceCommentContext::BetweenProperties
+            );
+        }
+        if matched + 1 == COMMENT_OPENING.len() {
+            self.state = SourceState::InComment(start, 0, context);
+            return None;
+        }
+        self.state = SourceState::StartComment(start, matched + 1, context);
+        None
+    }
+
+    fn handle_inside_comment(&mut self, start: usize, dashes: usize, context: SourceCommentContext, character: char) -> SourceTokenOption {
+        if character == '>' && dashes >= 2 {
+            self.state = match context {
+                SourceCommentContext::BetweenControls => SourceState::Start,
+                SourceCommentContext::BetweenProperties => SourceState::InWhitespace
+            };
+            return None;
+        }
+        if character == '-' {
+            self.state = SourceState::InComment(start, dashes + 1, context);
+            return None;
+        }
+        self.state = SourceState::InComment(start, 0, context);
+        None
+    }
 }
\ No newline at end of file
@@ -2,11 +2,12 @@ use crate::prelude::*;
 
 pub trait SourceTokenVisitor {
     fn token_error(&mut self, error: SourceTokenError);
-    fn control(&mut self, control_name: &str);
-    fn property(&mut self, property_name: &str);
-    fn variable_property(&mut self, variable_name: &str);
-    fn property_value(&mut self, property_value: &SourceTokenPropertyValue);
-    fn end_control(&mut self, control_name: &str);
+    fn control(&mut self, control_name: &str, span: &SourceSpan);
+    fn property(&mut self, property_name: &str, span: &SourceSpan);
+    fn variable_property(&mut self, variable_name: &str, span: &SourceSpan);
+    fn property_value(&mut self, property_value: &SourceTokenPropertyValue, span: &SourceSpan);
+    fn text(&mut self, text: &str, span: &SourceSpan);
+    fn end_control(&mut self, control_name: &str, span: &SourceSpan);
 }
 
 pub struct SourceTokenVisitationNavigator<'a> {
@@ -23,14 +24,15 @@ impl<'a> SourceTokenVisitationNavigator<'a> {
     pub fn accept(self, visitor: &mut impl SourceTokenVisitor) {
         for token_result in self.tokenizer {
             match token_result {
-                Ok(token) => match token {
-                    SourceToken::Control(control_name) => visitor.control(&control_name),
+                Ok(token) => match token.token() {
+                    SourceToken::Control(control_name) => visitor.control(control_name, token.span()),
                     SourceToken::Property(property_type, property_name) => match property_type {
-                        SourceTokenPropertyType::Standard => visitor.property(&property_name),
-                        SourceTokenPropertyType::Variable =>  visitor.variable_property(&property_name),
+                        SourceTokenPropertyType::Standard => visitor.property(property_name, token.span()),
+                        SourceTokenPropertyType::Variable =>  visitor.variable_property(property_name, token.span()),
                     }
-                    SourceToken::PropertyValue(property_value) => visitor.property_value(&property_value),
-                    SourceToken::EndControl(control_name) => visitor.end_control(&control_name),
+                    SourceToken::PropertyValue(property_value) => visitor.property_value(property_value, token.span()),
+                    SourceToken::Text(text) => visitor.text(text, token.span()),
+                    SourceToken::EndControl(control_name) => visitor.end_control(control_name, token.span()),
                 },
                 Err(error) => visitor.token_error(error),
             }
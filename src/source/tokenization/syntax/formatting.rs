@@ -0,0 +1,210 @@
+use crate::prelude::*;
+
+const DEFAULT_INDENTATION: &str = "    ";
+const IDENTIFIER_PROPERTY: &str = "id";
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SourceFormatError {
+    UnterminatedToken(SourceSpan),
+    UnterminatedTag(SourceSpan),
+    UnclosedControl(SourceSpan),
+    UnopenedControl(SourceSpan),
+    MissingControlName(SourceSpan),
+    UnexpectedValue(SourceSpan),
+    MissingValue(SourceSpan)
+}
+
+impl SourceFormatError {
+    pub fn span(&self) -> &SourceSpan {
+        match self {
+            SourceFormatError::UnterminatedToken(span) |
+            SourceFormatError::UnterminatedTag(span) |
+            SourceFormatError::UnclosedControl(span) |
+            SourceFormatError::UnopenedControl(span) |
+            SourceFormatError::MissingControlName(span) |
+            SourceFormatError::UnexpectedValue(span) |
+            SourceFormatError::MissingValue(span) => span
+        }
+    }
+}
+
+impl std::fmt::Display for SourceFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            SourceFormatError::UnterminatedToken(_) => "unterminated token",
+            SourceFormatError::UnterminatedTag(_) => "unterminated tag",
+            SourceFormatError::UnclosedControl(_) => "control is never closed",
+            SourceFormatError::UnopenedControl(_) => "closing tag has no open control",
+            SourceFormatError::MissingControlName(_) => "missing control name",
+            SourceFormatError::UnexpectedValue(_) => "unexpected value",
+            SourceFormatError::MissingValue(_) => "property is missing a value"
+        };
+        write!(f, "{}: {}", self.span(), message)
+    }
+}
+
+pub type SourceFormatResult = Result<String, SourceFormatError>;
+
+struct SourceFormatProperty<'a> {
+    comments: Vec<&'a str>,
+    name: &'a str,
+    value: Option<&'a str>
+}
+
+impl<'a> SourceFormatProperty<'a> {
+    fn order(&self) -> (u8, &'a str) {
+        if self.name.starts_with('$') {
+            return (0, "");
+        }
+        if self.name == IDENTIFIER_PROPERTY {
+            return (1, "");
+        }
+        (2, self.name)
+    }
+}
+
+pub struct SourceFormatter {
+    indentation: String
+}
+
+impl Default for SourceFormatter {
+    fn default() -> Self {
+        Self {
+            indentation: String::from(DEFAULT_INDENTATION)
+        }
+    }
+}
+
+impl SourceFormatter {
+    pub fn format_string(&self, input: &str) -> SourceFormatResult {
+        self.format(&SyntaxTree::from_string(input))
+    }
+
+    pub fn format(&self, tree: &SyntaxTree) -> SourceFormatResult {
+        let mut lines = vec!();
+        self.format_nodes(tree.nodes(), 0, &mut lines)?;
+        Ok(lines.into_iter().map(|line| line + "\n").collect())
+    }
+
+    fn format_nodes(&self, nodes: &[SyntaxNode], depth: usize, lines: &mut Vec<String>) -> Result<(), SourceFormatError> {
+        let first_line = lines.len();
+        let mut blank_line = false;
+        for node in nodes {
+            match node {
+                SyntaxNode::Token(token) if token.kind() == SyntaxTokenKind::Whitespace => {
+                    blank_line = token.text().matches('\n').count() > 1;
+                    continue;
+                },
+                SyntaxNode::Token(token) if token.kind() == SyntaxTokenKind::Unterminated => {
+                    return Err(SourceFormatError::UnterminatedToken(token.span().clone()));
+                },
+                SyntaxNode::UnopenedTag(tag) => {
+                    return Err(SourceFormatError::UnopenedControl(tag.span().clone()));
+                },
+                _ => {}
+            }
+            if blank_line && lines.len() > first_line {
+                lines.push(String::new());
+            }
+            blank_line = false;
+            match node {
+                SyntaxNode::Control(control) => self.format_control(control, depth, lines)?,
+                SyntaxNode::Token(token) if token.kind() == SyntaxTokenKind::Text => {
+                    lines.push(self.indent(depth) + &Self::format_text(token.text()));
+                },
+                SyntaxNode::Token(token) => lines.push(self.indent(depth) + token.text()),
+                SyntaxNode::UnopenedTag(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn format_control(&self, control: &SyntaxControl, depth: usize, lines: &mut Vec<String>) -> Result<(), SourceFormatError> {
+        let opening = control.opening();
+        let tag = Self::format_tag(opening)?;
+        if !opening.is_terminated() {
+            return Err(SourceFormatError::UnterminatedTag(opening.span().clone()));
+        }
+        if !control.is_closed() {
+            return Err(SourceFormatError::UnclosedControl(opening.span().clone()));
+        }
+        let name = match control.name() {
+            Some(name) => name,
+            None => return Err(SourceFormatError::MissingControlName(opening.span().clone()))
+        };
+        let children = control.children().iter()
+            .filter(|child| !matches!(child, SyntaxNode::Token(token) if token.kind() == SyntaxTokenKind::Whitespace))
+            .collect::<Vec<&SyntaxNode>>();
+        match children.as_slice() {
+            [] => {
+                lines.push(format!("{}<{} />", self.indent(depth), tag));
+            },
+            [SyntaxNode::Token(text)] if text.kind() == SyntaxTokenKind::Text => {
+                lines.push(format!("{}<{}>{}</{}>", self.indent(depth), tag, Self::format_text(text.text()), name));
+            },
+            _ => {
+                lines.push(format!("{}<{}>", self.indent(depth), tag));
+                self.format_nodes(control.children(), depth + 1, lines)?;
+                lines.push(format!("{}</{}>", self.indent(depth), name));
+            }
+        }
+        Ok(())
+    }
+
+    fn format_tag(tag: &SyntaxTag) -> SourceFormatResult {
+        let mut name = None;
+        let mut properties = vec!();
+        let mut comments = vec!();
+        let mut equals: Option<&SyntaxToken> = None;
+        for token in tag.tokens() {
+            if let Some(equals) = equals.take().filter(|_| !matches!(token.kind(), SyntaxTokenKind::Value | SyntaxTokenKind::Unterminated)) {
+                return Err(SourceFormatError::MissingValue(equals.span().clone()));
+            }
+            match token.kind() {
+                SyntaxTokenKind::Name if name.is_none() => {
+                    name = Some(token.text());
+                },
+                SyntaxTokenKind::Name => {
+                    properties.push(SourceFormatProperty {
+                        comments: std::mem::take(&mut comments),
+                        name: token.text(),
+                        value: None
+                    });
+                },
+                SyntaxTokenKind::Comment => {
+                    comments.push(token.text());
+                },
+                SyntaxTokenKind::Equals => {
+                    equals = Some(token);
+                },
+                SyntaxTokenKind::Value => match properties.last_mut() {
+                    Some(property) if property.value.is_none() => property.value = Some(token.text()),
+                    _ => return Err(SourceFormatError::UnexpectedValue(token.span().clone()))
+                },
+                SyntaxTokenKind::Unterminated => {
+                    return Err(SourceFormatError::UnterminatedToken(token.span().clone()));
+                },
+                _ => {}
+            }
+        }
+        properties.sort_by_key(|property| property.order());
+        let mut parts = vec!(name.unwrap_or_default().to_string());
+        for property in properties {
+            parts.extend(property.comments.iter().map(|comment| comment.to_string()));
+            parts.push(match property.value {
+                Some(value) => format!("{}={}", property.name, value),
+                None => property.name.to_string()
+            });
+        }
+        parts.extend(comments.iter().map(|comment| comment.to_string()));
+        Ok(parts.join(" "))
+    }
+
+    fn format_text(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<&str>>().join(" ")
+    }
+
+    fn indent(&self, depth: usize) -> String {
+        self.indentation.repeat(depth)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+mod tokeniser;
+mod tree;
+mod formatting;
+
+pub use tokeniser::*;
+pub use tree::*;
+pub use formatting::*;
\ No newline at end of file
@@ -0,0 +1,382 @@
+use crate::prelude::*;
+
+use std::collections::VecDeque;
+
+const COMMENT_START: char = '!';
+const COMMENT_OPENING_LENGTH: usize = 4;
+const COMMENT_CLOSING_DASHES: usize = 2;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SyntaxTokenKind {
+    Whitespace,
+    Text,
+    Comment,
+    TagOpening,
+    TagClosing,
+    Slash,
+    Equals,
+    Name,
+    Value,
+    Unterminated
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SyntaxToken {
+    kind: SyntaxTokenKind,
+    text: String,
+    span: SourceSpan
+}
+
+impl SyntaxToken {
+    pub fn new(kind: SyntaxTokenKind, text: String, span: SourceSpan) -> Self {
+        Self {
+            kind,
+            text,
+            span
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxTokenKind {
+        self.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn span(&self) -> &SourceSpan {
+        &self.span
+    }
+
+    pub fn is_trivia(&self) -> bool {
+        matches!(self.kind, SyntaxTokenKind::Whitespace | SyntaxTokenKind::Comment)
+    }
+}
+
+impl std::fmt::Display for SyntaxToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+pub type SyntaxTokenOption = Option<SyntaxToken>;
+
+#[derive(PartialEq, Eq, Debug)]
+enum SyntaxState {
+    Content,
+    Tag,
+    StartTag(usize, bool),
+    InComment(usize, usize, bool),
+    InWhitespace(usize, bool),
+    InText(usize, usize),
+    InName(usize),
+    AfterEquals,
+    InValue(usize),
+    InStringValue(usize, char, bool),
+    InArrayValue(usize, DelimitedValue),
+    InCodeValue(usize, DelimitedValue)
+}
+
+pub fn tokenize_syntax(from: &str, location: SourceLocation) -> Vec<SyntaxToken> {
+    SyntaxTokenizer::from_string(from).with_location(location).collect()
+}
+
+pub struct SyntaxTokenizer<'a> {
+    input: &'a str,
+    characters: CharIndices<'a>,
+    positions: SourcePositions,
+    pending: VecDeque<SyntaxToken>,
+    state: SyntaxState
+}
+
+impl <'a> Iterator for SyntaxTokenizer<'a> {
+    type Item = SyntaxToken;
+    fn next(&mut self) -> SyntaxTokenOption {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(token);
+            }
+            return match self.characters.next() {
+                Some((index, c)) => match self.advance_and_transition(index, c) {
+                    None => continue,
+                    result => result
+                },
+                None => self.finish()
+            }
+        }
+    }
+}
+
+impl<'a> SyntaxTokenizer<'a> {
+    pub fn from_string(input: &'a str) -> Self {
+        Self {
+            input,
+            characters: input.char_indices(),
+            positions: SourcePositions::default(),
+            pending: VecDeque::new(),
+            state: SyntaxState::Content
+        }
+    }
+
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.positions = SourcePositions::new(location, SourcePosition::default());
+        self
+    }
+
+    fn advance_and_transition(&mut self, index: usize, character: char) -> SyntaxTokenOption {
+        self.positions.advance(index, character);
+        self.transition(index, character)
+    }
+
+    fn transition(&mut self, index: usize, character: char) -> SyntaxTokenOption {
+        match self.state {
+            SyntaxState::Content => {
+                self.start_content_if_possible(index, character)
+            },
+            SyntaxState::Tag => {
+                self.start_tag_token_if_possible(index, character)
+            },
+            SyntaxState::StartTag(start, in_tag) => {
+                self.start_tag_if_possible(start, in_tag, index, character)
+            },
+            SyntaxState::InComment(start, dashes, in_tag) => {
+                self.handle_inside_comment(start, dashes, in_tag, index, character)
+            },
+            SyntaxState::InWhitespace(start, in_tag) => {
+                self.handle_inside_whitespace(start, in_tag, index, character)
+            },
+            SyntaxState::InText(start, end) => {
+                self.handle_inside_text(start, end, index, character)
+            },
+            SyntaxState::InName(start) => {
+                self.handle_inside_name(start, index, character)
+            },
+            SyntaxState::AfterEquals => {
+                self.start_value_if_possible(index, character)
+            },
+            SyntaxState::InValue(start) => {
+                self.handle_inside_value(start, index, character)
+            },
+            SyntaxState::InStringValue(start, quote, escaped) => {
+                self.handle_inside_string_value(start, quote, escaped, index, character)
+            },
+            SyntaxState::InArrayValue(start, delimited) => {
+                self.handle_inside_array_value(start, delimited, index, character)
+            },
+            SyntaxState::InCodeValue(start, delimited) => {
+                self.handle_inside_code_value(start, delimited, index, character)
+            }
+        }
+    }
+
+    fn finish(&mut self) -> SyntaxTokenOption {
+        let end = self.input.len();
+        let result = match self.state {
+            SyntaxState::StartTag(start, _) => {
+                self.produce_token(SyntaxTokenKind::TagOpening, start, end)
+            },
+            SyntaxState::InWhitespace(start, _) => {
+                self.produce_token(SyntaxTokenKind::Whitespace, start, end)
+            },
+            SyntaxState::InText(start, text_end) => {
+                self.produce_text_result(start, text_end, end)
+            },
+            SyntaxState::InName(start) => {
+                self.produce_token(SyntaxTokenKind::Name, start, end)
+            },
+            SyntaxState::InValue(start) => {
+                self.produce_token(SyntaxTokenKind::Value, start, end)
+            },
+            SyntaxState::InComment(start, _, _) |
+            SyntaxState::InStringValue(start, _, _) |
+            SyntaxState::InArrayValue(start, _) |
+            SyntaxState::InCodeValue(start, _) => {
+                self.produce_token(SyntaxTokenKind::Unterminated, start, end)
+            },
+            SyntaxState::Content | SyntaxState::Tag | SyntaxState::AfterEquals => None
+        };
+        self.state = SyntaxState::Content;
+        result
+    }
+
+    fn splice_input(&self, from: usize, to: usize) -> &'a str {
+        &self.input[from..to]
+    }
+
+    fn produce_token(&self, kind: SyntaxTokenKind, start: usize, end: usize) -> SyntaxTokenOption {
+        if start == end {
+            return None;
+        }
+        Some(SyntaxToken::new(kind, String::from(self.splice_input(start, end)), self.positions.span(start, end)))
+    }
+
+    fn restart_with(&mut self, result: SyntaxTokenOption, in_tag: bool, index: usize, character: char) -> SyntaxTokenOption {
+        self.pending.extend(result);
+        let next_result = match in_tag {
+            true => self.start_tag_token_if_possible(index, character),
+            false => self.start_content_if_possible(index, character)
+        };
+        self.pending.extend(next_result);
+        self.pending.pop_front()
+    }
+
+    fn start_content_if_possible(&mut self, index: usize, character: char) -> SyntaxTokenOption {
+        if character == '<' {
+            self.state = SyntaxState::StartTag(index, false);
+            return None;
+        }
+        if character.is_whitespace() {
+            self.state = SyntaxState::InWhitespace(index, false);
+            return None;
+        }
+        self.state = SyntaxState::InText(index, index + character.len_utf8());
+        None
+    }
+
+    fn start_tag_token_if_possible(&mut self, index: usize, character: char) -> SyntaxTokenOption {
+        if character == '<' {
+            self.state = SyntaxState::StartTag(index, true);
+            return None;
+        }
+        if character.is_whitespace() {
+            self.state = SyntaxState::InWhitespace(index, true);
+            return None;
+        }
+        if character == '>' {
+            self.state = SyntaxState::Content;
+            return self.produce_token(SyntaxTokenKind::TagClosing, index, index + 1);
+        }
+        if character == '/' {
+            self.state = SyntaxState::Tag;
+            return self.produce_token(SyntaxTokenKind::Slash, index, index + 1);
+        }
+        if character == '=' {
+            self.state = SyntaxState::AfterEquals;
+            return self.produce_token(SyntaxTokenKind::Equals, index, index + 1);
+        }
+        self.state = SyntaxState::InName(index);
+        None
+    }
+
+    fn start_tag_if_possible(&mut self, start: usize, in_tag: bool, index: usize, character: char) -> SyntaxTokenOption {
+        if character == COMMENT_START {
+            self.state = SyntaxState::InComment(start, 0, in_tag);
+            return None;
+        }
+        let result = self.produce_token(SyntaxTokenKind::TagOpening, start, index);
+        self.restart_with(result, true, index, character)
+    }
+
+    fn handle_inside_comment(&mut self, start: usize, dashes: usize, in_tag: bool, index: usize, character: char) -> SyntaxTokenOption {
+        let closing_start = start + COMMENT_OPENING_LENGTH + COMMENT_CLOSING_DASHES;
+        if character == '>' && dashes >= COMMENT_CLOSING_DASHES && index >= closing_start {
+            self.state = match in_tag {
+                true => SyntaxState::Tag,
+                false => SyntaxState::Content
+            };
+            return self.produce_token(SyntaxTokenKind::Comment, start, index + 1);
+        }
+        let dashes = match character {
+            '-' => dashes + 1,
+            _ => 0
+        };
+        self.state = SyntaxState::InComment(start, dashes, in_tag);
+        None
+    }
+
+    fn handle_inside_whitespace(&mut self, start: usize, in_tag: bool, index: usize, character: char) -> SyntaxTokenOption {
+        if character.is_whitespace() {
+            return None;
+        }
+        let result = self.produce_token(SyntaxTokenKind::Whitespace, start, index);
+        self.restart_with(result, in_tag, index, character)
+    }
+
+    fn produce_text_result(&mut self, start: usize, end: usize, index: usize) -> SyntaxTokenOption {
+        let trailing_whitespace = self.produce_token(SyntaxTokenKind::Whitespace, end, index);
+        self.pending.extend(trailing_whitespace);
+        self.produce_token(SyntaxTokenKind::Text, start, end)
+    }
+
+    fn handle_inside_text(&mut self, start: usize, end: usize, index: usize, character: char) -> SyntaxTokenOption {
+        if character == '<' {
+            self.state = SyntaxState::StartTag(index, false);
+            return self.produce_text_result(start, end, index);
+        }
+        if !character.is_whitespace() {
+            self.state = SyntaxState::InText(start, index + character.len_utf8());
+        }
+        None
+    }
+
+    fn handle_inside_name(&mut self, start: usize, index: usize, character: char) -> SyntaxTokenOption {
+        if character.is_whitespace() || matches!(character, '<' | '>' | '/' | '=') {
+            let result = self.produce_token(SyntaxTokenKind::Name, start, index);
+            return self.restart_with(result, true, index, character);
+        }
+        None
+    }
+
+    fn start_value_if_possible(&mut self, index: usize, character: char) -> SyntaxTokenOption {
+        if is_string_quote(character) {
+            self.state = SyntaxState::InStringValue(index, character, false);
+            return None;
+        }
+        if character == ARRAY_OPENING_CHAR {
+            self.state = SyntaxState::InArrayValue(index, DelimitedValue::default());
+            return None;
+        }
+        if character == CODE_OPENING_CHAR {
+            self.state = SyntaxState::InCodeValue(index, DelimitedValue::default());
+            return None;
+        }
+        if character.is_whitespace() || matches!(character, '<' | '>' | '/') {
+            return self.start_tag_token_if_possible(index, character);
+        }
+        self.state = SyntaxState::InValue(index);
+        None
+    }
+
+    fn handle_inside_value(&mut self, start: usize, index: usize, character: char) -> SyntaxTokenOption {
+        if character.is_whitespace() || matches!(character, '<' | '>' | '/') {
+            let result = self.produce_token(SyntaxTokenKind::Value, start, index);
+            return self.restart_with(result, true, index, character);
+        }
+        None
+    }
+
+    fn handle_inside_string_value(&mut self, start: usize, quote: char, escaped: bool, index: usize, character: char) -> SyntaxTokenOption {
+        if character == quote && !escaped {
+            self.state = SyntaxState::Tag;
+            return self.produce_token(SyntaxTokenKind::Value, start, index + 1);
+        }
+        self.state = SyntaxState::InStringValue(start, quote, !escaped && character == STRING_ESCAPE_CHAR);
+        None
+    }
+
+    fn handle_inside_array_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char) -> SyntaxTokenOption {
+        match delimited.advance(ARRAY_OPENING_CHAR, ARRAY_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = SyntaxState::InArrayValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = SyntaxState::Tag;
+                self.produce_token(SyntaxTokenKind::Value, start, index + 1)
+            }
+        }
+    }
+
+    fn handle_inside_code_value(&mut self, start: usize, delimited: DelimitedValue, index: usize, character: char) -> SyntaxTokenOption {
+        match delimited.advance(CODE_OPENING_CHAR, CODE_CLOSING_CHAR, character) {
+            Some(delimited) => {
+                self.state = SyntaxState::InCodeValue(start, delimited);
+                None
+            },
+            None => {
+                self.state = SyntaxState::Tag;
+                self.produce_token(SyntaxTokenKind::Value, start, index + 1)
+            }
+        }
+    }
+}
\ No newline at end of file
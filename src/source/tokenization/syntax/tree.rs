@@ -0,0 +1,211 @@
+use crate::prelude::*;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SyntaxTag {
+    tokens: Vec<SyntaxToken>
+}
+
+impl SyntaxTag {
+    pub fn new(tokens: Vec<SyntaxToken>) -> Self {
+        Self {
+            tokens
+        }
+    }
+
+    pub fn tokens(&self) -> &Vec<SyntaxToken> {
+        &self.tokens
+    }
+
+    pub fn span(&self) -> &SourceSpan {
+        self.tokens[0].span()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.tokens.iter()
+            .find(|token| token.kind() == SyntaxTokenKind::Name)
+            .map(|token| token.text())
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        matches!(self.tokens.last(), Some(token) if token.kind() == SyntaxTokenKind::TagClosing)
+    }
+
+    pub fn is_closing(&self) -> bool {
+        matches!(self.significant_tokens().nth(1), Some(token) if token.kind() == SyntaxTokenKind::Slash)
+    }
+
+    pub fn is_self_closing(&self) -> bool {
+        let mut tokens = self.significant_tokens().rev();
+        matches!(
+            (tokens.next(), tokens.next()),
+            (Some(last), Some(slash)) if last.kind() == SyntaxTokenKind::TagClosing && slash.kind() == SyntaxTokenKind::Slash
+        ) && !self.is_closing()
+    }
+
+    fn significant_tokens(&self) -> impl DoubleEndedIterator<Item = &SyntaxToken> {
+        self.tokens.iter().filter(|token| !token.is_trivia())
+    }
+}
+
+impl std::fmt::Display for SyntaxTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.tokens.iter().try_for_each(|token| write!(f, "{}", token))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SyntaxControl {
+    opening: SyntaxTag,
+    children: Vec<SyntaxNode>,
+    closing: Option<SyntaxTag>
+}
+
+impl SyntaxControl {
+    pub fn new(opening: SyntaxTag) -> Self {
+        Self {
+            opening,
+            children: vec!(),
+            closing: None
+        }
+    }
+
+    pub fn opening(&self) -> &SyntaxTag {
+        &self.opening
+    }
+
+    pub fn children(&self) -> &Vec<SyntaxNode> {
+        &self.children
+    }
+
+    pub fn closing(&self) -> Option<&SyntaxTag> {
+        self.closing.as_ref()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.opening.name()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.opening.is_self_closing() || self.closing.is_some()
+    }
+}
+
+impl std::fmt::Display for SyntaxControl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.opening)?;
+        self.children.iter().try_for_each(|child| write!(f, "{}", child))?;
+        match self.closing() {
+            Some(closing) => write!(f, "{}", closing),
+            None => Ok(())
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SyntaxNode {
+    Token(SyntaxToken),
+    Control(SyntaxControl),
+    UnopenedTag(SyntaxTag)
+}
+
+impl std::fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Token(token) => write!(f, "{}", token),
+            Self::Control(control) => write!(f, "{}", control),
+            Self::UnopenedTag(tag) => write!(f, "{}", tag)
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SyntaxTree {
+    nodes: Vec<SyntaxNode>
+}
+
+impl SyntaxTree {
+    pub fn from_string(input: &str) -> Self {
+        Self::from_tokens(tokenize_syntax(input, SourceLocation::default()))
+    }
+
+    pub fn from_tokens(tokens: Vec<SyntaxToken>) -> Self {
+        SyntaxTreeBuilder::default().build(tokens)
+    }
+
+    pub fn nodes(&self) -> &Vec<SyntaxNode> {
+        &self.nodes
+    }
+}
+
+impl std::fmt::Display for SyntaxTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.nodes.iter().try_for_each(|node| write!(f, "{}", node))
+    }
+}
+
+#[derive(Default)]
+struct SyntaxTreeBuilder {
+    nodes: Vec<SyntaxNode>,
+    open_controls: Vec<SyntaxControl>
+}
+
+impl SyntaxTreeBuilder {
+    fn build(mut self, tokens: Vec<SyntaxToken>) -> SyntaxTree {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            if token.kind() != SyntaxTokenKind::TagOpening {
+                self.push_node(SyntaxNode::Token(token));
+                continue;
+            }
+            let mut tag_tokens = vec!(token);
+            while let Some(token) = tokens.next_if(|token| token.kind() != SyntaxTokenKind::TagOpening) {
+                let closing = token.kind() == SyntaxTokenKind::TagClosing;
+                tag_tokens.push(token);
+                if closing {
+                    break;
+                }
+            }
+            self.push_tag(SyntaxTag::new(tag_tokens));
+        }
+        while !self.open_controls.is_empty() {
+            self.close_last_control(None);
+        }
+        SyntaxTree {
+            nodes: self.nodes
+        }
+    }
+
+    fn push_node(&mut self, node: SyntaxNode) {
+        match self.open_controls.last_mut() {
+            Some(control) => control.children.push(node),
+            None => self.nodes.push(node)
+        }
+    }
+
+    fn push_tag(&mut self, tag: SyntaxTag) {
+        if !tag.is_closing() {
+            let control = SyntaxControl::new(tag);
+            match control.opening.is_self_closing() || !control.opening.is_terminated() {
+                true => self.push_node(SyntaxNode::Control(control)),
+                false => self.open_controls.push(control)
+            }
+            return;
+        }
+        match self.open_controls.iter().rposition(|control| control.name() == tag.name()) {
+            Some(position) => {
+                while self.open_controls.len() > position + 1 {
+                    self.close_last_control(None);
+                }
+                self.close_last_control(Some(tag));
+            },
+            None => self.push_node(SyntaxNode::UnopenedTag(tag))
+        }
+    }
+
+    fn close_last_control(&mut self, closing: Option<SyntaxTag>) {
+        if let Some(mut control) = self.open_controls.pop() {
+            control.closing = closing;
+            self.push_node(SyntaxNode::Control(control));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+#[test]
+fn comment_on_its_own_produces_no_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<!-- nothing to see here -->");
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn comment_before_control_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<!-- a rectangle --><rect/>");
+    assert_eq!(&SourceToken::Control(String::from("rect")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn comment_between_nested_controls_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect>
+        <!-- <circle/> -->
+        <line/>
+    </rect>");
+    assert_eq!(&SourceToken::Control(String::from("rect")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Control(String::from("line")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("line")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn comment_between_properties_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect width=10 <!-- height=\"20\" --> colour=\"red\"/>");
+    assert_eq!(&SourceToken::Control(String::from("rect")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("width")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::USize(10)), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("colour")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("red"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn comment_containing_dashes_and_closing_symbols_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<!-- a - b -> c > d ---><rect/>");
+    assert_eq!(&SourceToken::Control(String::from("rect")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn incomplete_comment_opening_produces_error_result() {
+    let mut tokenizer = SourceTokenizer::from_string("<!- nope -->");
+    assert_eq!(Err(SourceTokenError::CouldNotFindCommentStart(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 4)))), tokenizer.next().unwrap());
+}
+
+#[test]
+fn unterminated_comment_produces_error_result() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect/>\n<!-- <circle/>");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(Err(SourceTokenError::UnterminatedComment(SourceSpan::point(SourceLocation::default(), SourcePosition::new(2, 1)))), tokenizer.next().unwrap());
+    assert_eq!(None, tokenizer.next());
+}
@@ -0,0 +1,152 @@
+use crate::prelude::*;
+
+fn evaluate(code: &str, context: &mut DataContext) -> AbstractSyntaxPropertyValue {
+    let expression = Expression::parse(&tokenize_code(code, SourcePositions::default())).unwrap();
+    context.evaluate_expression(&expression).unwrap()
+}
+
+#[test]
+fn arithmetic_expression_respects_operator_precedence() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("count"), AbstractSyntaxPropertyValue::USize(4));
+    assert_eq!(14, evaluate("{2 + $count * 3}", &mut context).get_usize_value().unwrap());
+    assert_eq!(18, evaluate("{(2 + $count) * 3}", &mut context).get_usize_value().unwrap());
+    assert_eq!(1, evaluate("{$count % 3}", &mut context).get_usize_value().unwrap());
+    assert!(matches!(evaluate("{2 - $count}", &mut context), AbstractSyntaxPropertyValue::Int(-2)));
+    assert_eq!(2.5, evaluate("{$count / 2 + 0.5}", &mut context).get_float_value().unwrap());
+}
+
+#[test]
+fn integer_arithmetic_widens_only_when_mixed_with_floats() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("count"), AbstractSyntaxPropertyValue::USize(4));
+    assert!(matches!(evaluate("{-3 * $count}", &mut context), AbstractSyntaxPropertyValue::Int(-12)));
+    assert!(matches!(evaluate("{-$count + 1}", &mut context), AbstractSyntaxPropertyValue::Int(-3)));
+    assert!(matches!(evaluate("{-7 / 2}", &mut context), AbstractSyntaxPropertyValue::Int(-3)));
+    assert_eq!(-2.5, evaluate("{-3 + 0.5}", &mut context).get_float_value().unwrap());
+    assert!(evaluate("{-3 < $count}", &mut context).get_bool_value().unwrap());
+    assert!(evaluate("{-4 == -$count}", &mut context).get_bool_value().unwrap());
+}
+
+#[test]
+fn conditional_branches_can_be_bare_identifiers() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("selected"), AbstractSyntaxPropertyValue::Bool(true));
+    assert!(evaluate("{$selected ? true: false}", &mut context).get_bool_value().unwrap());
+    assert!(!evaluate("{!$selected ? true:false}", &mut context).get_bool_value().unwrap());
+}
+
+#[test]
+fn boolean_expression_combines_comparisons_and_negation() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("selected"), AbstractSyntaxPropertyValue::Bool(false));
+    context.set_variable(String::from("count"), AbstractSyntaxPropertyValue::USize(3));
+    assert!(evaluate("{!$selected}", &mut context).get_bool_value().unwrap());
+    assert!(evaluate("{!$selected && $count > 0}", &mut context).get_bool_value().unwrap());
+    assert!(!evaluate("{$selected || $count <= 2}", &mut context).get_bool_value().unwrap());
+    assert!(evaluate("{$count == 3 && 'a' != 'b'}", &mut context).get_bool_value().unwrap());
+}
+
+#[test]
+fn logical_operators_short_circuit_before_evaluating_missing_variables() {
+    let mut context = DataContext::default();
+    assert!(!evaluate("{false && $missing}", &mut context).get_bool_value().unwrap());
+    assert!(evaluate("{true || $missing}", &mut context).get_bool_value().unwrap());
+}
+
+#[test]
+fn string_concatenation_and_conditional_produce_text() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("count"), AbstractSyntaxPropertyValue::USize(2));
+    assert_eq!("items: 2", evaluate(r#"{$count > 0 ? "items: " + $count : "none"}"#, &mut context).get_string_value().unwrap());
+    context.set_variable(String::from("count"), AbstractSyntaxPropertyValue::USize(0));
+    assert_eq!("none", evaluate(r#"{$count > 0 ? "items: " + $count : "none"}"#, &mut context).get_string_value().unwrap());
+}
+
+#[test]
+fn single_function_call_parses_into_selector_value() {
+    let value = AbstractSyntaxPropertyValue::parse_code(&tokenize_code("{module::selected($state, $id + 1)}", SourcePositions::default())).unwrap();
+    match value {
+        AbstractSyntaxPropertyValue::Selector(function) => {
+            assert_eq!("module::selected", function.name());
+            assert!(function.arguments()[0].is_state_variable());
+            assert!(matches!(function.arguments()[1], AbstractSyntaxPropertyValue::Expression(_)));
+        },
+        _ => panic!("expected selector")
+    }
+}
+
+#[test]
+fn incomplete_expression_produces_parse_error() {
+    let result = Expression::parse(&tokenize_code("{1 + }", SourcePositions::default()));
+    assert!(matches!(result, Err(AbstractSyntaxTokenError::UnexpectedEndOfCode)));
+    let result = Expression::parse(&tokenize_code("{1 ? 2}", SourcePositions::default()));
+    assert!(matches!(result, Err(AbstractSyntaxTokenError::UnexpectedEndOfCode)));
+}
+
+struct GetUserSelectorContainer;
+
+impl SelectorContainer for GetUserSelectorContainer {
+    fn function_name(&self) -> &str {
+        "get_user"
+    }
+
+    fn run(&self, _data_arrays: &mut DataArrays, _state: &mut State, arguments: &Vec<AbstractSyntaxPropertyValue>) -> Result<AbstractSyntaxPropertyValue, ContainerRunError> {
+        if !arguments[0].is_state_variable() {
+            return Err(ContainerRunError::FirstArgumentNotStateVariable);
+        }
+        Ok(AbstractSyntaxPropertyValue::String(format!("user_{}", arguments[1].get_usize_value()?)))
+    }
+}
+
+struct FormatNameSelectorContainer;
+
+impl SelectorContainer for FormatNameSelectorContainer {
+    fn function_name(&self) -> &str {
+        "format_name"
+    }
+
+    fn run(&self, _data_arrays: &mut DataArrays, _state: &mut State, arguments: &Vec<AbstractSyntaxPropertyValue>) -> Result<AbstractSyntaxPropertyValue, ContainerRunError> {
+        Ok(AbstractSyntaxPropertyValue::String(format!("Name: {}", arguments[0].get_string_value()?)))
+    }
+}
+
+#[test]
+fn nested_function_call_parses_into_selector_argument() {
+    let function = Function::parse(&tokenize_code("{format_name(get_user($state, $id))}", SourcePositions::default())).unwrap();
+    assert_eq!("format_name", function.name());
+    match &function.arguments()[0] {
+        AbstractSyntaxPropertyValue::Selector(inner_function) => {
+            assert_eq!("get_user", inner_function.name());
+            assert_eq!(2, inner_function.arguments().len());
+        },
+        _ => panic!("expected nested selector")
+    }
+}
+
+#[test]
+fn run_selector_function_evaluates_inner_calls_first() {
+    let mut context = DataContext::default();
+    context.selectors_mut().register_selector(GetUserSelectorContainer);
+    context.selectors_mut().register_selector(FormatNameSelectorContainer);
+    context.set_variable(String::from("id"), AbstractSyntaxPropertyValue::USize(7));
+    let function = Function::parse(&tokenize_code("{format_name(get_user($state, $id + 1))}", SourcePositions::default())).unwrap();
+    assert_eq!("Name: user_8", context.run_selector_function(&function).unwrap().get_string_value().unwrap());
+    assert_eq!("Name: user_7!", evaluate("{format_name(get_user($state, $id)) + '!'}", &mut context).get_string_value().unwrap());
+}
+
+
+#[test]
+fn action_arguments_are_resolved_once_inner_calls_first() {
+    let mut context = create_data_context();
+    context.selectors_mut().register_selector(GetUserSelectorContainer);
+    context.selectors_mut().register_selector(FormatNameSelectorContainer);
+    context.set_variable(String::from("id"), AbstractSyntaxPropertyValue::USize(7));
+    let function = Function::parse(&tokenize_code("{ruxy::switch_root(format_name(get_user($state, $id)))}", SourcePositions::default())).unwrap();
+    let resolved = context.replace_variable_data_in_function(&function).unwrap();
+    assert_eq!("Name: user_7", resolved.arguments()[0].get_string_value().unwrap());
+
+    context.set_variable(String::from("id"), AbstractSyntaxPropertyValue::USize(8));
+    context.run_action_function(&resolved).unwrap();
+    assert_eq!(Some("Name: user_7"), context.active_root());
+}
\ No newline at end of file
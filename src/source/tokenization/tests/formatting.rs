@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+fn format(input: &str) -> SourceFormatResult {
+    SourceFormatter::default().format_string(input)
+}
+
+#[test]
+fn formatting_normalises_indentation() {
+    let input = "<root>\n  <vertical>\n\t\t<label text=\"a\" />\n      </vertical>\n</root>";
+    let expected = "<root>\n    <vertical>\n        <label text=\"a\" />\n    </vertical>\n</root>\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_self_closes_empty_controls() {
+    let input = "<root>\n    <separator></separator>\n    <central-panel>\n    </central-panel>\n</root>";
+    let expected = "<root>\n    <separator />\n    <central-panel />\n</root>\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_orders_properties() {
+    let input = "<label underline text=\"a\" id=\"b\" colour=[1, 2, 3, 4]/>";
+    let expected = "<label id=\"b\" colour=[1, 2, 3, 4] text=\"a\" underline />\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_keeps_variable_properties_first_and_in_order() {
+    let input = "<let id=\"a\" $b={1} $a=2></let>";
+    let expected = "<let $b={1} $a=2 id=\"a\" />\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_keeps_comments() {
+    let input = "<root>\n<!-- controls -->\n<rect width=10 <!-- the height --> height=20 <!-- end -->/>\n</root>";
+    let expected = "<root>\n    <!-- controls -->\n    <rect <!-- the height --> height=20 width=10 <!-- end --> />\n</root>\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_keeps_text_inline_and_collapses_whitespace() {
+    let input = "<root><heading>\n  a   heading\n</heading><vertical>some <label/> text</vertical></root>";
+    let expected = "<root>\n    <heading>a heading</heading>\n    <vertical>\n        some\n        <label />\n        text\n    </vertical>\n</root>\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_keeps_single_blank_lines_between_controls() {
+    let input = "<root>\n\n    <a/>\n\n\n\n    <b/>\n</root>";
+    let expected = "<root>\n    <a />\n\n    <b />\n</root>\n";
+    assert_eq!(Ok(String::from(expected)), format(input));
+}
+
+#[test]
+fn formatting_is_idempotent() {
+    let formatted = format(include_str!("../../../../examples/assets/first/app.rux")).unwrap();
+    assert_eq!(Ok(formatted.clone()), format(&formatted));
+}
+
+#[test]
+fn formatted_source_produces_same_source_tokens() {
+    let input = "<control>\n  <for $item_id=[0, 10]><let $selected={is_selected($state, $item_id)}>\n<selectable-label on-select={select_item($item_id)} selected=$selected text={\"item \" + $item_id}/>\n</let></for>\n<heading>\n  a   heading </heading></control>";
+    let formatted = format(input).unwrap();
+    let tokens = |input| SourceTokenizer::from_string(input)
+        .map(|result| result.map(|token| token.token().clone()))
+        .collect::<Vec<Result<SourceToken, SourceTokenError>>>();
+    assert_eq!(tokens(input), tokens(&formatted));
+}
+
+#[test]
+fn formatting_unclosed_control_produces_error() {
+    assert_eq!(
+        Err(SourceFormatError::UnclosedControl(SourceSpan::new(SourceLocation::default(), SourcePosition::new(1, 7), SourcePosition::new(1, 8)))),
+        format("<root><a></root>")
+    );
+}
+
+#[test]
+fn formatting_unopened_control_produces_error() {
+    assert_eq!(
+        Err(SourceFormatError::UnopenedControl(SourceSpan::new(SourceLocation::default(), SourcePosition::new(1, 8), SourcePosition::new(1, 9)))),
+        format("<root/></a>")
+    );
+}
+
+#[test]
+fn formatting_missing_value_produces_error() {
+    assert_eq!(
+        Err(SourceFormatError::MissingValue(SourceSpan::new(SourceLocation::default(), SourcePosition::new(1, 8), SourcePosition::new(1, 9)))),
+        format("<rect a= b/>")
+    );
+}
+
+#[test]
+fn formatting_unterminated_value_produces_error() {
+    assert!(matches!(format("<rect text=\"abc/>"), Err(SourceFormatError::UnterminatedToken(_))));
+}
+
+#[test]
+fn formatting_error_displays_its_span() {
+    let error = format("<root><a></root>").unwrap_err();
+    assert_eq!(SourcePosition::new(1, 7), error.span().start());
+    assert_eq!("1:7: control is never closed", error.to_string());
+}
+
+fn scroll_area_size(source: &str) -> Option<Size> {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", source);
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+    let graph = ast.graph();
+    let scroll_area = graph.get_children(graph.get_root().unwrap())[0];
+    ScrollAreaProperties::try_from(scroll_area.properties()).unwrap().size
+}
+
+#[test]
+fn formatted_scroll_area_keeps_its_size() {
+    for input in ["<root><scroll-area max-height=100 auto-sized /></root>", "<root><scroll-area auto-sized max-height=100 /></root>"] {
+        let formatted = format(input).unwrap();
+        assert_eq!("<root>\n    <scroll-area auto-sized max-height=100 />\n</root>\n", formatted);
+        assert_eq!(None, scroll_area_size(input));
+        assert_eq!(scroll_area_size(input), scroll_area_size(&formatted));
+    }
+    assert_eq!(Some(Size::points(100.0)), scroll_area_size("<root><scroll-area max-height=100 /></root>"));
+}
\ No newline at end of file
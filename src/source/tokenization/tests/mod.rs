@@ -1,6 +1,21 @@
 #[cfg(test)]
+mod comments;
+#[cfg(test)]
 mod control;
 #[cfg(test)]
 mod properties;
+#[cfg(test)]
+mod spans;
+#[cfg(test)]
+mod text;
+#[cfg(test)]
+mod recovery;
+#[cfg(test)]
+mod expressions;
+#[cfg(test)]
+mod unicode;
 
-
+#[cfg(test)]
+mod syntax;
+#[cfg(test)]
+mod formatting;
\ No newline at end of file
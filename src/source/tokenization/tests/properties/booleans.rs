@@ -3,20 +3,20 @@ use crate::prelude::*;
 #[test]
 fn property_without_value_produces_boolean_property_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect large-size />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("large-size")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("large-size")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn multiple_properties_without_value_produces_boolean_properties_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect large-size rounded-edges other />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("large-size")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("rounded-edges")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("large-size")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("rounded-edges")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
@@ -24,11 +24,66 @@ fn multiple_properties_without_value_produces_boolean_properties_result_inside_c
 #[test]
 fn multiple_nested_controls_with_valueless_properties_produces_correct_tokens() {
     let mut tokenizer = SourceTokenizer::from_string("<canvas other><circle other></circle></canvas>");
-    assert_eq!(SourceToken::Control(String::from("canvas")),  tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Control(String::from("circle")),  tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("circle")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("canvas")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("canvas")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Control(String::from("circle")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("other")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("circle")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("canvas")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
+
+#[test]
+fn property_with_bool_literal_values_produces_bool_value_results_inside_control() {
+    let mut tokenizer = SourceTokenizer::from_string("<label strong=true wrap=false/>");
+    assert_eq!(&SourceToken::Control(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("strong")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Bool(true)), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("wrap")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Bool(false)), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn property_with_bool_literal_value_in_opening_control_produces_bool_value_result() {
+    let mut tokenizer = SourceTokenizer::from_string("<panel resizable=false></panel>");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Bool(false)), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("panel")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn property_with_unknown_literal_value_produces_error_result() {
+    let mut tokenizer = SourceTokenizer::from_string("<label strong=yes />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(Err(SourceTokenError::CouldNotParseIdentifierValue(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 15)))), tokenizer.next().unwrap());
+}
+
+#[test]
+fn bool_literals_inside_arrays_and_functions_produce_bool_values() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect flags=[true,false] on-click={toggle(true, 1)} />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(SourceTokenPropertyValue::Array(vec!(
+            Ok(SourceTokenPropertyValue::Bool(true)),
+            Ok(SourceTokenPropertyValue::Bool(false))
+        ))),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(SourceTokenPropertyValue::Code(vec!(
+            Ok(CodeTokenPropertyValue::StartFunction(String::from("toggle"))),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Bool(true))),
+            Ok(CodeTokenPropertyValue::ArgumentSeparator),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(1))),
+            Ok(CodeTokenPropertyValue::EndFunction)
+        ))),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
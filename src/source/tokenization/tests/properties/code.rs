@@ -4,56 +4,57 @@ use crate::prelude::*;
 #[test]
 fn property_with_variable_property_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect position=$item_x />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("position")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from("item_x"))), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("position")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from("item_x"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn property_with_variable_property_value_in_opening_control_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect position=$item_x></rect>");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("position")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from("item_x"))), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("position")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from("item_x"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn property_with_function_value_produces_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect on-click={click_it(1, 2)} />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap().token());
     
     assert_eq!(
-        SourceToken::PropertyValue(
+        &SourceToken::PropertyValue(
             SourceTokenPropertyValue::Code(
                 vec!(
                     Ok(CodeTokenPropertyValue::StartFunction(String::from("click_it"))),
                     Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(1))),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
                     Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(2))),
                     Ok(CodeTokenPropertyValue::EndFunction),
 
                 )
             )
         ),
-        tokenizer.next().unwrap().unwrap()
+        tokenizer.next().unwrap().unwrap().token()
     );
 
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn property_with_function_value_containing_variable_argument_produces_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect on-click={click_it($item)} />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap().token());
     
     assert_eq!(
-        SourceToken::PropertyValue(
+        &SourceToken::PropertyValue(
             SourceTokenPropertyValue::Code(
                 vec!(
                     Ok(CodeTokenPropertyValue::StartFunction(String::from("click_it"))),
@@ -63,46 +64,47 @@ fn property_with_function_value_containing_variable_argument_produces_property_a
                 )
             )
         ),
-        tokenizer.next().unwrap().unwrap()
+        tokenizer.next().unwrap().unwrap().token()
     );
 
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn property_with_multi_argument_function_value_containing_variable_argument_produces_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect on-click={click_it($item, 2)} />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("on-click")), tokenizer.next().unwrap().unwrap().token());
     
     assert_eq!(
-        SourceToken::PropertyValue(
+        &SourceToken::PropertyValue(
             SourceTokenPropertyValue::Code(
                 vec!(
                     Ok(CodeTokenPropertyValue::StartFunction(String::from("click_it"))),
                     Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("item")))),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
                     Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(2))),
                     Ok(CodeTokenPropertyValue::EndFunction),
 
                 )
             )
         ),
-        tokenizer.next().unwrap().unwrap()
+        tokenizer.next().unwrap().unwrap().token()
     );
 
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
 
 #[test]
 fn variable_property_with_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect $x=[1, 2] />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Variable, String::from("x")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Variable, String::from("x")), tokenizer.next().unwrap().unwrap().token());
     
     assert_eq!(
-        SourceToken::PropertyValue(
+        &SourceToken::PropertyValue(
             SourceTokenPropertyValue::Array(
                 vec!(
                     Ok(SourceTokenPropertyValue::USize(1)),
@@ -110,9 +112,181 @@ fn variable_property_with_property_and_value_result_inside_control() {
                 )
             )
         ),
-        tokenizer.next().unwrap().unwrap()
+        tokenizer.next().unwrap().unwrap().token()
     );
 
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
+}
+#[test]
+fn property_with_function_value_with_quoted_strings_produces_unescaped_arguments() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect on-click={say("hi \"you\"", 'it\'s', 3)} />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::StartFunction(String::from("say"))),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("hi \"you\"")))),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("it's")))),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(3))),
+                    Ok(CodeTokenPropertyValue::EndFunction),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+fn property_with_function_value_with_bad_escape_produces_error_argument() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect on-click={say("\u{zz}")} />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::StartFunction(String::from("say"))),
+                    Err(CodeTokenError::InvalidStringEscape(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 22)))),
+                    Ok(CodeTokenPropertyValue::EndFunction),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+fn property_with_expression_value_produces_operator_and_operand_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<label visible={!$selected && ($count - 1) >= -2} />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::Not)),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("selected")))),
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::And)),
+                    Ok(CodeTokenPropertyValue::StartGroup),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("count")))),
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::Subtract)),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(1))),
+                    Ok(CodeTokenPropertyValue::EndGroup),
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::GreaterOrEqual)),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Int(-2))),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+fn property_with_conditional_expression_produces_conditional_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<label text={$count > 0 ? "items: " + $count : 'none'} />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("count")))),
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::Greater)),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::USize(0))),
+                    Ok(CodeTokenPropertyValue::Conditional),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("items: ")))),
+                    Ok(CodeTokenPropertyValue::Operator(CodeOperator::Add)),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("count")))),
+                    Ok(CodeTokenPropertyValue::ConditionalElse),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("none")))),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+fn property_with_unknown_operator_produces_error_token() {
+    let mut tokenizer = SourceTokenizer::from_string("<label visible={$a = $b} />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("a")))),
+                    Err(CodeTokenError::UnknownOperator(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 20)), String::from("="))),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("b")))),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+fn property_with_nested_function_calls_produces_nested_function_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<label text={format_name(get_user($state, $id), 'short')} />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Code(
+                vec!(
+                    Ok(CodeTokenPropertyValue::StartFunction(String::from("format_name"))),
+                    Ok(CodeTokenPropertyValue::StartFunction(String::from("get_user"))),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("state")))),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("id")))),
+                    Ok(CodeTokenPropertyValue::EndFunction),
+                    Ok(CodeTokenPropertyValue::ArgumentSeparator),
+                    Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("short")))),
+                    Ok(CodeTokenPropertyValue::EndFunction),
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+
+#[test]
+fn unknown_bare_identifier_produces_unknown_identifier_error() {
+    assert_eq!(
+        vec!(
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("a")))),
+            Ok(CodeTokenPropertyValue::Conditional),
+            Err(CodeTokenError::UnknownIdentifier(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 7)), String::from("yes"))),
+            Ok(CodeTokenPropertyValue::ConditionalElse),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Bool(false)))
+        ),
+        tokenize_code("{$a ? yes: false}", SourcePositions::default())
+    );
+}
+
+#[test]
+fn path_separator_is_only_part_of_function_names() {
+    assert_eq!(
+        vec!(
+            Ok(CodeTokenPropertyValue::StartFunction(String::from("module::inner::selected"))),
+            Ok(CodeTokenPropertyValue::EndFunction)
+        ),
+        tokenize_code("{module::inner::selected()}", SourcePositions::default())
+    );
+    assert_eq!(
+        vec!(
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("a")))),
+            Ok(CodeTokenPropertyValue::Conditional),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Bool(true))),
+            Ok(CodeTokenPropertyValue::ConditionalElse),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Bool(false)))
+        ),
+        tokenize_code("{$a ? true: false}", SourcePositions::default())
+    );
 }
\ No newline at end of file
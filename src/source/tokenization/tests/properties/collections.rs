@@ -3,11 +3,11 @@ use crate::prelude::*;
 #[test]
 pub fn property_with_array_value_produces_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect size=[1.0, 2, -5, \"xxx\"] />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("size")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("size")), tokenizer.next().unwrap().unwrap().token());
     
     assert_eq!(
-        SourceToken::PropertyValue(
+        &SourceToken::PropertyValue(
             SourceTokenPropertyValue::Array(
                 vec!(
                     Ok(SourceTokenPropertyValue::Float(1.0)),
@@ -17,9 +17,141 @@ pub fn property_with_array_value_produces_property_and_value_result_inside_contr
                 )
             )
         ),
-        tokenizer.next().unwrap().unwrap()
+        tokenizer.next().unwrap().unwrap().token()
     );
 
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
 }
+
+#[test]
+pub fn property_with_array_of_quoted_strings_produces_unescaped_values() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect names=["a\"b", 'c,d' , "\u{41}\\"] />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::String(String::from("a\"b"))),
+                    Ok(SourceTokenPropertyValue::String(String::from("c,d"))),
+                    Ok(SourceTokenPropertyValue::String(String::from("A\\")))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+pub fn property_with_array_containing_bad_escape_produces_error_value() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect names=["\x"] />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Err(ArrayTokenError::InvalidStringEscape(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 15))))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
+
+#[test]
+pub fn property_with_nested_arrays_produces_nested_array_values() {
+    let mut tokenizer = SourceTokenizer::from_string("<table cells=[[0, 1], [2, [3]]] />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::Array(vec!(
+                        Ok(SourceTokenPropertyValue::USize(0)),
+                        Ok(SourceTokenPropertyValue::USize(1))
+                    ))),
+                    Ok(SourceTokenPropertyValue::Array(vec!(
+                        Ok(SourceTokenPropertyValue::USize(2)),
+                        Ok(SourceTokenPropertyValue::Array(vec!(
+                            Ok(SourceTokenPropertyValue::USize(3))
+                        )))
+                    )))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    assert_eq!(&SourceToken::EndControl(String::from("table")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+pub fn property_with_array_of_variables_and_selectors_produces_mixed_values() {
+    let mut tokenizer = SourceTokenizer::from_string("<for $i=[0, $state.count, {length($items)}]></for>");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::USize(0)),
+                    Ok(SourceTokenPropertyValue::Variable(String::from("state.count"))),
+                    Ok(SourceTokenPropertyValue::Code(vec!(
+                        Ok(CodeTokenPropertyValue::StartFunction(String::from("length"))),
+                        Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("items")))),
+                        Ok(CodeTokenPropertyValue::EndFunction)
+                    )))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    assert_eq!(&SourceToken::EndControl(String::from("for")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+pub fn property_with_trailing_comma_and_newlines_in_array_produces_values() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect size=[\n    1,\n    \"two\" ,\n    [3,],\n] />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::USize(1)),
+                    Ok(SourceTokenPropertyValue::String(String::from("two"))),
+                    Ok(SourceTokenPropertyValue::Array(vec!(
+                        Ok(SourceTokenPropertyValue::USize(3))
+                    )))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+pub fn nested_array_errors_report_position_inside_nested_array() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect size=[[1, 2 3]] />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::Array(vec!(
+                        Ok(SourceTokenPropertyValue::USize(1)),
+                        Ok(SourceTokenPropertyValue::USize(2)),
+                        Err(ArrayTokenError::NoClosingParenthesis(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 19))))
+                    )))
+                )
+            )
+        ),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+}
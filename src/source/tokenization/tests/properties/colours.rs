@@ -0,0 +1,98 @@
+use crate::prelude::*;
+
+struct AccentSelectorContainer;
+
+impl SelectorContainer for AccentSelectorContainer {
+    fn function_name(&self) -> &str {
+        "accent"
+    }
+
+    fn run(&self, _data_arrays: &mut DataArrays, _state: &mut State, arguments: &Vec<AbstractSyntaxPropertyValue>) -> Result<AbstractSyntaxPropertyValue, ContainerRunError> {
+        match arguments[0].get_bool_value()? {
+            true => Ok(AbstractSyntaxPropertyValue::String(String::from("#ff8000"))),
+            false => Ok(AbstractSyntaxPropertyValue::String(String::from("navy")))
+        }
+    }
+}
+
+fn property_value(input: &str) -> Result<SourceToken, SourceTokenError> {
+    let mut tokenizer = SourceTokenizer::from_string(input);
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next().unwrap().map(|token| token.token().clone())
+}
+
+#[test]
+fn property_with_hex_colour_value_produces_colour_value_result() {
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour([255, 0, 16, 255]))), property_value("<rect colour=#ff0010 />"));
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour([171, 205, 239, 128]))), property_value("<rect colour=#ABCDEF80/>"));
+}
+
+#[test]
+fn property_with_named_colour_value_produces_colour_value_result() {
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour([255, 0, 0, 255]))), property_value("<rect colour=red>"));
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Colour([0, 0, 0, 0]))), property_value("<rect colour=transparent />"));
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Bool(true))), property_value("<rect visible=true />"));
+}
+
+#[test]
+fn property_with_invalid_colour_value_produces_error_result() {
+    let error = SourceTokenError::CouldNotParseColourValue(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 14)));
+    assert_eq!(Err(error.clone()), property_value("<rect colour=#ff00 />"));
+    assert_eq!(Err(error), property_value("<rect colour=#gg0000 />"));
+    assert_eq!(
+        Err(SourceTokenError::CouldNotParseIdentifierValue(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 14)))),
+        property_value("<rect colour=reddish />")
+    );
+}
+
+#[test]
+fn colours_inside_arrays_and_code_produce_colour_values() {
+    assert_eq!(
+        vec!(
+            Ok(SourceTokenPropertyValue::Colour([0, 255, 0, 255])),
+            Ok(SourceTokenPropertyValue::Colour([0, 128, 128, 255])),
+            Err(ArrayTokenError::ParseColourError(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 17)), String::from("#12")))
+        ),
+        tokenize_array("[#00ff00, teal, #12]", SourcePositions::default())
+    );
+    assert_eq!(
+        vec!(
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("on")))),
+            Ok(CodeTokenPropertyValue::Conditional),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Colour([255, 255, 255, 255]))),
+            Ok(CodeTokenPropertyValue::ConditionalElse),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Colour([17, 34, 51, 255])))
+        ),
+        tokenize_code("{$on ? white : #112233}", SourcePositions::default())
+    );
+}
+
+#[test]
+fn colour_literal_parses_hex_and_named_colours() {
+    assert_eq!(Ok(Colour { r: 1, g: 2, b: 3, a: 255 }), Colour::parse_literal("#010203"));
+    assert_eq!(Ok(Colour { r: 128, g: 128, b: 128, a: 255 }), Colour::parse_literal("grey"));
+    assert_eq!(Err(AbstractSyntaxTokenError::ColourValueParseError), Colour::parse_literal("#01020"));
+    assert_eq!(Err(AbstractSyntaxTokenError::ColourValueParseError), Colour::parse_literal("rainbow"));
+}
+
+#[test]
+fn colour_returned_by_selector_produces_colour_value() {
+    let mut context = DataContext::default();
+    context.selectors_mut().register_selector(AccentSelectorContainer);
+    let function = Function::parse(&tokenize_code("{accent(true)}", SourcePositions::default())).unwrap();
+    let value = context.run_selector_function(&function).unwrap();
+    assert_eq!(Colour { r: 255, g: 128, b: 0, a: 255 }, value.get_colour_value().unwrap());
+    let function = Function::parse(&tokenize_code("{accent(false)}", SourcePositions::default())).unwrap();
+    let value = context.run_selector_function(&function).unwrap();
+    assert_eq!(Colour { r: 0, g: 0, b: 128, a: 255 }, value.get_colour_value().unwrap());
+}
+
+#[test]
+fn colour_expression_evaluates_to_colour_value() {
+    let mut context = DataContext::default();
+    context.set_variable(String::from("on"), AbstractSyntaxPropertyValue::Bool(false));
+    let expression = Expression::parse(&tokenize_code("{$on ? white : #112233}", SourcePositions::default())).unwrap();
+    let value = context.evaluate_expression(&expression).unwrap();
+    assert_eq!(Colour { r: 17, g: 34, b: 51, a: 255 }, value.get_colour_value().unwrap());
+}
\ No newline at end of file
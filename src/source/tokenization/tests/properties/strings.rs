@@ -3,9 +3,55 @@ use crate::prelude::*;
 #[test]
 fn property_with_string_value_produces_property_and_value_result_inside_control() {
     let mut tokenizer = SourceTokenizer::from_string("<rect size=\"large\" />");
-    assert_eq!(SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("size")), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("large"))), tokenizer.next().unwrap().unwrap());
-    assert_eq!(SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap());
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("size")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("large"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
     assert_eq!(None, tokenizer.next());
-}
\ No newline at end of file
+}
+
+#[test]
+fn property_with_single_quoted_string_value_produces_property_and_value_result_inside_control() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect size='say \"large\"' />");
+    assert_eq!(&SourceToken::Control(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("size")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("say \"large\""))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn property_with_escaped_string_value_produces_unescaped_value_result_inside_control() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect text="a \"quote\"\n\ttab \\ \u{1F600} \'" />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("a \"quote\"\n\ttab \\ \u{1F600} '"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("rect")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn property_with_escaped_single_quote_produces_unescaped_value_result_inside_control() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect text='it\'s' />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("it's"))), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn property_with_unknown_escape_produces_error_result() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<rect text="bad \q" />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(Err(SourceTokenError::InvalidStringEscape(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 17)))), tokenizer.next().unwrap());
+}
+
+#[test]
+fn property_with_malformed_unicode_escape_produces_error_result() {
+    for source in [r#"<rect text="\u{}" />"#, r#"<rect text="\u{110000}" />"#, r#"<rect text="\u{12" />"#, r#"<rect text="\u12" />"#] {
+        let mut tokenizer = SourceTokenizer::from_string(source);
+        tokenizer.next();
+        tokenizer.next();
+        assert_eq!(Err(SourceTokenError::InvalidStringEscape(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 13)))), tokenizer.next().unwrap());
+    }
+}
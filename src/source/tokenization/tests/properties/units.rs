@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+fn property_value(input: &str) -> Result<SourceToken, SourceTokenError> {
+    let mut tokenizer = SourceTokenizer::from_string(input);
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next().unwrap().map(|token| token.token().clone())
+}
+
+#[test]
+fn property_with_unit_suffix_produces_size_value_result() {
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Size(50.0, SizeUnit::Percent))), property_value("<side-panel default-width=50% />"));
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Size(1.5, SizeUnit::Em))), property_value("<side-panel default-width=1.5em>"));
+    assert_eq!(Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Float(12.5))), property_value("<side-panel default-width=12.5 />"));
+}
+
+#[test]
+fn array_with_unit_suffixes_produces_size_value_results() {
+    assert_eq!(
+        Ok(SourceToken::PropertyValue(
+            SourceTokenPropertyValue::Array(
+                vec!(
+                    Ok(SourceTokenPropertyValue::Size(10.0, SizeUnit::Em)),
+                    Ok(SourceTokenPropertyValue::Size(50.0, SizeUnit::Percent))
+                )
+            )
+        )),
+        property_value("<side-panel width-range=[10em, 50%] />")
+    );
+}
+
+#[test]
+fn property_with_invalid_unit_produces_error_result() {
+    assert!(property_value("<side-panel default-width=50px />").is_err());
+}
+
+#[test]
+fn size_resolves_against_available_space_and_font_size() {
+    assert_eq!(120.0, Size::points(120.0).resolve(800.0, 14.0));
+    assert_eq!(400.0, Size::new(50.0, SizeUnit::Percent).resolve(800.0, 14.0));
+    assert_eq!(28.0, Size::new(2.0, SizeUnit::Em).resolve(800.0, 14.0));
+}
+
+#[test]
+fn size_range_parses_mixed_units_and_resolves_both_ends() {
+    let values = match property_value("<side-panel width-range=[10em, 50%] />") {
+        Ok(SourceToken::PropertyValue(SourceTokenPropertyValue::Array(values))) => values,
+        other => panic!("expected array value, got {:?}", other)
+    };
+    let range = SizeRange::parse(&values).unwrap();
+    assert_eq!(140.0..=300.0, range.resolve(600.0, 14.0));
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+fn tokenize(source: &str) -> Vec<Result<SourceToken, SourceTokenError>> {
+    SourceTokenizer::from_string(source)
+        .map(|result| result.map(|token| token.token().clone()))
+        .collect()
+}
+
+fn control(name: &str) -> Result<SourceToken, SourceTokenError> {
+    Ok(SourceToken::Control(String::from(name)))
+}
+
+fn end_control(name: &str) -> Result<SourceToken, SourceTokenError> {
+    Ok(SourceToken::EndControl(String::from(name)))
+}
+
+fn point(line: usize, column: usize) -> SourceSpan {
+    SourceSpan::point(SourceLocation::default(), SourcePosition::new(line, column))
+}
+
+#[test]
+fn stray_text_before_control_recovers_at_next_control() {
+    assert_eq!(
+        vec!(
+            Err(SourceTokenError::CouldNotFindStartTag(point(1, 1))),
+            control("rect"),
+            end_control("rect")
+        ),
+        tokenize("oops <rect/>")
+    );
+}
+
+#[test]
+fn missing_control_close_symbol_recovers_at_next_control() {
+    assert_eq!(
+        vec!(
+            control("root"),
+            control("label"),
+            Err(SourceTokenError::CouldNotFindControlCloseSymbol(point(3, 5))),
+            end_control("label"),
+            control("separator"),
+            end_control("separator"),
+            end_control("root")
+        ),
+        tokenize("<root>\n    <label\n    <separator/>\n</root>")
+    );
+}
+
+#[test]
+fn invalid_property_value_recovers_at_end_of_control() {
+    assert_eq!(
+        vec!(
+            control("rect"),
+            Ok(SourceToken::Property(SourceTokenPropertyType::Standard, String::from("width"))),
+            Err(SourceTokenError::CouldNotFindPropertyStartSymbol(point(1, 13))),
+            end_control("rect"),
+            control("circle"),
+            end_control("circle")
+        ),
+        tokenize("<rect width=@oops \"/>\" /><circle/>")
+    );
+}
+
+#[test]
+fn closing_ancestor_control_closes_unclosed_children() {
+    assert_eq!(
+        vec!(
+            control("a"),
+            control("b"),
+            control("c"),
+            Err(SourceTokenError::UnclosedControl(point(1, 8))),
+            end_control("c"),
+            Err(SourceTokenError::UnclosedControl(point(1, 5))),
+            end_control("b"),
+            end_control("a")
+        ),
+        tokenize("<a><b><c></a>")
+    );
+}
+
+#[test]
+fn closing_unknown_control_is_ignored() {
+    assert_eq!(
+        vec!(
+            control("a"),
+            Err(SourceTokenError::ClosingWrongTag(point(1, 7))),
+            end_control("a")
+        ),
+        tokenize("<a></b></a>")
+    );
+}
+
+#[test]
+fn unclosed_controls_are_closed_at_end_of_input() {
+    assert_eq!(
+        vec!(
+            control("a"),
+            control("b"),
+            Err(SourceTokenError::UnclosedControl(point(1, 5))),
+            end_control("b"),
+            Err(SourceTokenError::UnclosedControl(point(1, 2))),
+            end_control("a")
+        ),
+        tokenize("<a><b>")
+    );
+}
+
+#[test]
+fn every_error_in_source_is_reported() {
+    let errors: Vec<SourceTokenError> = tokenize("<root>
+    <label width=1.2.3 />
+    < label />
+    <label strong=maybe />
+    <!- label -->
+</root>")
+        .into_iter()
+        .filter_map(|result| result.err())
+        .collect();
+
+    assert_eq!(
+        vec!(
+            SourceTokenError::CouldNotParseNumberValue(point(2, 23)),
+            SourceTokenError::CouldNotFindControlName(point(3, 6)),
+            SourceTokenError::CouldNotParseIdentifierValue(point(4, 19)),
+            SourceTokenError::CouldNotFindCommentStart(point(5, 8))
+        ),
+        errors
+    );
+}
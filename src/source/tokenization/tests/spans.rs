@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+#[test]
+fn tokens_on_multiple_lines_produce_line_and_column_spans() {
+    let mut tokenizer = SourceTokenizer::from_string("<canvas>
+    <rect size=10 />
+</canvas>");
+    let canvas = tokenizer.next().unwrap().unwrap();
+    assert_eq!(SourcePosition::new(1, 2), canvas.span().start());
+    assert_eq!(SourcePosition::new(1, 8), canvas.span().end());
+    let rect = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::Control(String::from("rect")), rect.token());
+    assert_eq!(SourcePosition::new(2, 6), rect.span().start());
+    let size = tokenizer.next().unwrap().unwrap();
+    assert_eq!(SourcePosition::new(2, 11), size.span().start());
+    let size_value = tokenizer.next().unwrap().unwrap();
+    assert_eq!(SourcePosition::new(2, 16), size_value.span().start());
+    assert_eq!(SourcePosition::new(2, 18), size_value.span().end());
+    tokenizer.next();
+    let end_canvas = tokenizer.next().unwrap().unwrap();
+    assert_eq!(SourcePosition::new(3, 3), end_canvas.span().start());
+}
+
+#[test]
+fn error_on_later_line_produces_line_and_column_span() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect>
+  <line size=1x />");
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(Err(SourceTokenError::CouldNotParseNumberValue(SourceSpan::point(SourceLocation::default(), SourcePosition::new(2, 16)))), tokenizer.next().unwrap());
+}
+
+#[test]
+fn error_inside_code_value_produces_span_relative_to_source() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect>
+  <line on-click={click_it(1x)} />");
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next();
+    match tokenizer.next().unwrap().unwrap().token() {
+        SourceToken::PropertyValue(SourceTokenPropertyValue::Code(tokens)) => 
+            assert_eq!(&SourcePosition::new(2, 30), &tokens[1].as_ref().unwrap_err().span().start()),
+        other => panic!("{:?}", other)
+    }
+}
+
+#[test]
+fn span_with_location_displays_file_line_and_column() {
+    let mut tokenizer = SourceTokenizer::from_string("<rect />\n X")
+        .with_location(SourceLocation::from("selections.rux"));
+    tokenizer.next();
+    tokenizer.next();
+    let error = tokenizer.next().unwrap().unwrap_err();
+    assert_eq!("selections.rux:2:2", error.span().to_string());
+}
@@ -0,0 +1,115 @@
+use crate::prelude::*;
+
+fn assert_round_trip(input: &str) {
+    assert_eq!(input, SyntaxTree::from_string(input).to_string());
+}
+
+fn token_kinds(input: &str) -> Vec<SyntaxTokenKind> {
+    SyntaxTokenizer::from_string(input).map(|token| token.kind()).collect()
+}
+
+#[test]
+fn example_sources_round_trip() {
+    assert_round_trip(include_str!("../../../../examples/assets/first/app.rux"));
+    assert_round_trip(include_str!("../../../../examples/assets/first/selections.rux"));
+}
+
+#[test]
+fn irregular_layout_round_trips() {
+    assert_round_trip("\n\t<root   >\r\n  <label text = \"a\"\ttext-style='heading'/>  text  with   spaces \n</root >  \n");
+    assert_round_trip("<rect width=10 <!-- height=\"20\" --> colour={rgb(1, 2, 3)} range=[0, [1, 2]]/>");
+    assert_round_trip("<!-- a - b -> c > d ---><rect/>");
+}
+
+#[test]
+fn malformed_sources_round_trip() {
+    assert_round_trip("<rect width=10 <line/>");
+    assert_round_trip("</rect><rect><line></rect>");
+    assert_round_trip("<rect text=\"unterminated />");
+    assert_round_trip("<rect><!-- unterminated");
+    assert_round_trip("<rect = > <");
+}
+
+#[test]
+fn control_with_properties_produces_correct_tokens() {
+    assert_eq!(vec!(
+        SyntaxTokenKind::TagOpening,
+        SyntaxTokenKind::Name,
+        SyntaxTokenKind::Whitespace,
+        SyntaxTokenKind::Name,
+        SyntaxTokenKind::Equals,
+        SyntaxTokenKind::Value,
+        SyntaxTokenKind::Whitespace,
+        SyntaxTokenKind::Comment,
+        SyntaxTokenKind::Whitespace,
+        SyntaxTokenKind::Name,
+        SyntaxTokenKind::Slash,
+        SyntaxTokenKind::TagClosing
+    ), token_kinds("<rect text=\"a > b\" <!-- c --> strong/>"));
+}
+
+#[test]
+fn text_keeps_trailing_whitespace_as_separate_token() {
+    let tokens = SyntaxTokenizer::from_string("<a> some  text \n</a>").collect::<Vec<SyntaxToken>>();
+    assert_eq!(SyntaxTokenKind::Whitespace, tokens[3].kind());
+    assert_eq!(SyntaxTokenKind::Text, tokens[4].kind());
+    assert_eq!("some  text", tokens[4].text());
+    assert_eq!(SyntaxTokenKind::Whitespace, tokens[5].kind());
+    assert_eq!(" \n", tokens[5].text());
+}
+
+#[test]
+fn tokens_have_correct_spans() {
+    let tokens = tokenize_syntax("<a>\n  <b/>\n</a>", SourceLocation::default());
+    let name = tokens.iter().filter(|token| token.kind() == SyntaxTokenKind::Name).nth(1).unwrap();
+    assert_eq!("b", name.text());
+    assert_eq!(SourcePosition::new(2, 4), name.span().start());
+    assert_eq!(SourcePosition::new(2, 5), name.span().end());
+}
+
+#[test]
+fn unterminated_value_produces_unterminated_token() {
+    assert_eq!(vec!(
+        SyntaxTokenKind::TagOpening,
+        SyntaxTokenKind::Name,
+        SyntaxTokenKind::Whitespace,
+        SyntaxTokenKind::Name,
+        SyntaxTokenKind::Equals,
+        SyntaxTokenKind::Unterminated
+    ), token_kinds("<rect colour=[1, 2"));
+}
+
+#[test]
+fn tree_nests_controls() {
+    let tree = SyntaxTree::from_string("<root><a/><b>text</b></root>");
+    let root = match &tree.nodes()[0] {
+        SyntaxNode::Control(control) => control,
+        node => panic!("unexpected node {:?}", node)
+    };
+    assert_eq!(Some("root"), root.name());
+    assert!(root.is_closed());
+    assert_eq!(2, root.children().len());
+    match &root.children()[1] {
+        SyntaxNode::Control(control) => {
+            assert_eq!(Some("b"), control.name());
+            assert_eq!("</b>", control.closing().unwrap().to_string());
+        },
+        node => panic!("unexpected node {:?}", node)
+    }
+}
+
+#[test]
+fn tree_keeps_unclosed_and_unopened_controls() {
+    let tree = SyntaxTree::from_string("<root><a></root></b>");
+    match &tree.nodes()[..] {
+        [SyntaxNode::Control(root), SyntaxNode::UnopenedTag(tag)] => {
+            assert!(root.is_closed());
+            match &root.children()[0] {
+                SyntaxNode::Control(control) => assert!(!control.is_closed()),
+                node => panic!("unexpected node {:?}", node)
+            }
+            assert_eq!(Some("b"), tag.name());
+        },
+        nodes => panic!("unexpected nodes {:?}", nodes)
+    }
+}
\ No newline at end of file
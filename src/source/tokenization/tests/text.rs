@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+#[test]
+fn text_inside_control_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<label>Hello world</label>");
+    assert_eq!(&SourceToken::Control(String::from("label")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Text(String::from("Hello world")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn text_after_properties_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<heading id=\"title\">Settings</heading>");
+    assert_eq!(&SourceToken::Control(String::from("heading")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("id")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("title"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Text(String::from("Settings")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("heading")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn text_whitespace_is_normalised() {
+    let mut tokenizer = SourceTokenizer::from_string("<label>
+        Hello
+            big    wide\tworld
+    </label>");
+    tokenizer.next();
+    assert_eq!(&SourceToken::Text(String::from("Hello big wide world")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn text_with_variables_is_kept_verbatim() {
+    let mut tokenizer = SourceTokenizer::from_string("<label>Hello $user.name, welcome back</label>");
+    tokenizer.next();
+    assert_eq!(&SourceToken::Text(String::from("Hello $user.name, welcome back")),  tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn text_around_child_controls_produces_correct_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<horizontal>before<separator/>after</horizontal>");
+    assert_eq!(&SourceToken::Control(String::from("horizontal")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Text(String::from("before")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Control(String::from("separator")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("separator")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::Text(String::from("after")),  tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("horizontal")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn text_span_starts_at_first_character() {
+    let mut tokenizer = SourceTokenizer::from_string("<label>\n    Hello</label>");
+    tokenizer.next();
+    let token = tokenizer.next().unwrap().unwrap();
+    assert_eq!(SourcePosition::new(2, 5), token.span().start());
+}
+
+#[test]
+fn text_outside_control_produces_error_result() {
+    let mut tokenizer = SourceTokenizer::from_string("Hello");
+    assert_eq!(Err(SourceTokenError::CouldNotFindStartTag(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 1)))), tokenizer.next().unwrap());
+}
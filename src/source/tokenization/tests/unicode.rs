@@ -0,0 +1,134 @@
+use crate::prelude::*;
+
+#[test]
+fn unicode_text_produces_text_token_and_character_spans() {
+    let mut tokenizer = SourceTokenizer::from_string("<label>Grüße, 世界 👋</label>");
+    assert_eq!(&SourceToken::Control(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+    let text = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::Text(String::from("Grüße, 世界 👋")), text.token());
+    assert_eq!(SourcePosition::new(1, 8), text.span().start());
+    assert_eq!(SourcePosition::new(1, 19), text.span().end());
+    let end_label = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::EndControl(String::from("label")), end_label.token());
+    assert_eq!(SourcePosition::new(1, 21), end_label.span().start());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn unicode_control_and_property_names_produce_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<étiquette café=1 $naïve=2 />");
+    assert_eq!(&SourceToken::Control(String::from("étiquette")), tokenizer.next().unwrap().unwrap().token());
+    let property = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Standard, String::from("café")), property.token());
+    assert_eq!(SourcePosition::new(1, 12), property.span().start());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::USize(1)), tokenizer.next().unwrap().unwrap().token());
+    let variable = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::Property(SourceTokenPropertyType::Variable, String::from("naïve")), variable.token());
+    assert_eq!(SourcePosition::new(1, 19), variable.span().start());
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::USize(2)), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("étiquette")), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(None, tokenizer.next());
+}
+
+#[test]
+fn unicode_string_values_produce_string_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string(r#"<label text="日本語 ✓" tip='ça "va"' escaped="\u{1F600}é" />"#);
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("日本語 ✓"))), tokenizer.next().unwrap().unwrap().token());
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("ça \"va\""))), tokenizer.next().unwrap().unwrap().token());
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::String(String::from("😀é"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn unicode_variable_value_produces_variable_token() {
+    let mut tokenizer = SourceTokenizer::from_string("<label text=$élément.nom />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(&SourceToken::PropertyValue(SourceTokenPropertyValue::Variable(String::from("élément.nom"))), tokenizer.next().unwrap().unwrap().token());
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn unicode_inside_array_produces_array_values() {
+    let mut tokenizer = SourceTokenizer::from_string("<list items=[\"α\", [\"β\", $γ], {f('δ')}] />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(SourceTokenPropertyValue::Array(vec!(
+            Ok(SourceTokenPropertyValue::String(String::from("α"))),
+            Ok(SourceTokenPropertyValue::Array(vec!(
+                Ok(SourceTokenPropertyValue::String(String::from("β"))),
+                Ok(SourceTokenPropertyValue::Variable(String::from("γ")))
+            ))),
+            Ok(SourceTokenPropertyValue::Code(vec!(
+                Ok(CodeTokenPropertyValue::StartFunction(String::from("f"))),
+                Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("δ")))),
+                Ok(CodeTokenPropertyValue::EndFunction)
+            )))
+        ))),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    assert_eq!(&SourceToken::EndControl(String::from("list")), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn unicode_inside_code_produces_code_tokens() {
+    let mut tokenizer = SourceTokenizer::from_string("<label text={\"名前: \" + $ユーザー + übersetzen('ß')} />");
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(
+        &SourceToken::PropertyValue(SourceTokenPropertyValue::Code(vec!(
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("名前: ")))),
+            Ok(CodeTokenPropertyValue::Operator(CodeOperator::Add)),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::Variable(String::from("ユーザー")))),
+            Ok(CodeTokenPropertyValue::Operator(CodeOperator::Add)),
+            Ok(CodeTokenPropertyValue::StartFunction(String::from("übersetzen"))),
+            Ok(CodeTokenPropertyValue::PropertyValue(SourceTokenPropertyValue::String(String::from("ß")))),
+            Ok(CodeTokenPropertyValue::EndFunction)
+        ))),
+        tokenizer.next().unwrap().unwrap().token()
+    );
+    assert_eq!(&SourceToken::EndControl(String::from("label")), tokenizer.next().unwrap().unwrap().token());
+}
+
+#[test]
+fn unicode_comment_is_skipped() {
+    let mut tokenizer = SourceTokenizer::from_string("<root><!-- ünïcödé — 注释 --><label /></root>");
+    assert_eq!(&SourceToken::Control(String::from("root")), tokenizer.next().unwrap().unwrap().token());
+    let label = tokenizer.next().unwrap().unwrap();
+    assert_eq!(&SourceToken::Control(String::from("label")), label.token());
+    assert_eq!(SourcePosition::new(1, 29), label.span().start());
+}
+
+#[test]
+fn errors_after_unicode_report_character_columns() {
+    let mut tokenizer = SourceTokenizer::from_string("<label text=\"日本\" size=1x />");
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next();
+    assert_eq!(Err(SourceTokenError::CouldNotParseNumberValue(SourceSpan::point(SourceLocation::default(), SourcePosition::new(1, 25)))), tokenizer.next().unwrap());
+}
+
+#[test]
+fn errors_inside_nested_values_after_unicode_report_character_columns() {
+    let mut tokenizer = SourceTokenizer::from_string("<é>\n  <ü v=[\"ö\", \"\\q\"] c={f(\"ä\", \"\\q\")} />\n</é>");
+    tokenizer.next();
+    tokenizer.next();
+    tokenizer.next();
+    match tokenizer.next().unwrap().unwrap().token() {
+        SourceToken::PropertyValue(SourceTokenPropertyValue::Array(values)) =>
+            assert_eq!(&SourcePosition::new(2, 15), &values[1].as_ref().unwrap_err().span().start()),
+        other => panic!("{:?}", other)
+    }
+    tokenizer.next();
+    match tokenizer.next().unwrap().unwrap().token() {
+        SourceToken::PropertyValue(SourceTokenPropertyValue::Code(tokens)) =>
+            assert_eq!(&SourcePosition::new(2, 31), &tokens[3].as_ref().unwrap_err().span().start()),
+        other => panic!("{:?}", other)
+    }
+}
@@ -0,0 +1,20 @@
+const PERCENT_SUFFIX: &str = "%";
+const EM_SUFFIX: &str = "em";
+
+#[derive(PartialEq, Eq, PartialOrd, Debug, Clone, Copy)]
+pub enum SizeUnit {
+    Points,
+    Percent,
+    Em
+}
+
+pub fn parse_size_value(raw_value: &str) -> Option<(f64, SizeUnit)> {
+    let (number, unit) = if let Some(number) = raw_value.strip_suffix(PERCENT_SUFFIX) {
+        (number, SizeUnit::Percent)
+    } else if let Some(number) = raw_value.strip_suffix(EM_SUFFIX) {
+        (number, SizeUnit::Em)
+    } else {
+        return None;
+    };
+    number.parse::<f64>().ok().map(|value| (value, unit))
+}
\ No newline at end of file
@@ -18,4 +18,8 @@ impl RegisteredActions {
     pub fn get_action_container(&self, function_name: &str) -> Option<&Box<dyn ActionContainer>> {
         self.actions.get(function_name)
     }
+
+    pub fn function_names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.actions.keys().map(String::as_str)
+    }
 }
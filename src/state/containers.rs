@@ -7,6 +7,16 @@ pub enum ContainerRunError {
     PropertyValueError(AbstractSyntaxPropertyValueError)
 }
 
+impl std::fmt::Display for ContainerRunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IncorrectAmountOfArgumentsPassed => write!(f, "incorrect amount of arguments passed"),
+            Self::FirstArgumentNotStateVariable => write!(f, "first argument is not a state variable"),
+            Self::PropertyValueError(error) => write!(f, "{}", error)
+        }
+    }
+}
+
 impl From<AbstractSyntaxPropertyValueError> for ContainerRunError {
     fn from(from: AbstractSyntaxPropertyValueError) -> Self {
         Self::PropertyValueError(from)
@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
 pub fn create_data_context() -> DataContext {
-    DataContext::default()
+    let mut context = DataContext::default();
+    context.actions_mut().register_action(SwitchRootActionContainer);
+    context
 }
 
 #[derive(Debug)]
@@ -10,7 +12,25 @@ pub enum DataContextError {
     DataArrayItemDoesNotExist(VariablePath),
     DataArrayDoesNotExist,
     VariableDoesNotExist,
-    ContainerNotFound
+    ContainerNotFound(String, Option<String>),
+    PropertyValueError(AbstractSyntaxPropertyValueError)
+}
+
+impl std::fmt::Display for DataContextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ActionRunError(error) => write!(f, "{}", error),
+            Self::DataArrayItemDoesNotExist(path) => write!(f, "data array item {:?} does not exist", path),
+            Self::DataArrayDoesNotExist => write!(f, "data array does not exist"),
+            Self::VariableDoesNotExist => write!(f, "variable does not exist"),
+            Self::ContainerNotFound(name, suggestion) => write!(
+                f,
+                "{}",
+                with_suggestion(format!("function {:?} was not found", name), suggestion.as_deref())
+            ),
+            Self::PropertyValueError(error) => write!(f, "{}", error)
+        }
+    }
 }
 
 
@@ -20,10 +40,17 @@ impl From<ContainerRunError> for DataContextError {
     }
 }
 
+impl From<AbstractSyntaxPropertyValueError> for DataContextError {
+    fn from(from: AbstractSyntaxPropertyValueError) -> Self {
+        Self::PropertyValueError(from)
+    }
+}
+
 #[derive(Default)]
 pub struct DataContext { 
     actions: RegisteredActions,
     selectors: RegisteredSelectors,
+    controls: RegisteredControls,
     data_arrays: DataArrays,
     state: State,
     variables: HashMap<String, AbstractSyntaxPropertyValue> 
@@ -35,14 +62,17 @@ impl DataContext {
             container.run(&mut self.state, &function.arguments())?;
             return Ok(());
         }
-        Err(DataContextError::ContainerNotFound)
+        let suggestion = closest_match(function.name(), self.actions.function_names()).map(String::from);
+        Err(DataContextError::ContainerNotFound(function.name().to_string(), suggestion))
     }
 
     pub fn run_selector_function(&mut self, function: &Function) -> Result<AbstractSyntaxPropertyValue, DataContextError> {
+        let function = self.replace_variable_data_in_function(function)?;
         if let Some(container) = self.selectors.get_selector_container(function.name()) {
             return Ok(container.run(&mut self.data_arrays, &mut self.state, &function.arguments())?);
         }
-        Err(DataContextError::ContainerNotFound)
+        let suggestion = closest_match(function.name(), self.selectors.function_names()).map(String::from);
+        Err(DataContextError::ContainerNotFound(function.name().to_string(), suggestion))
     }
 
     pub fn data_arrays(&self) -> &DataArrays {
@@ -53,14 +83,38 @@ impl DataContext {
         &mut self.data_arrays
     }
 
+    pub fn actions(&self) -> &RegisteredActions {
+        &self.actions
+    }
+
     pub fn actions_mut(&mut self) -> &mut RegisteredActions {
         &mut self.actions
     }
 
+    pub fn selectors(&self) -> &RegisteredSelectors {
+        &self.selectors
+    }
+
     pub fn selectors_mut(&mut self) -> &mut RegisteredSelectors {
         &mut self.selectors
     }
 
+    pub fn controls(&self) -> &RegisteredControls {
+        &self.controls
+    }
+
+    pub fn controls_mut(&mut self) -> &mut RegisteredControls {
+        &mut self.controls
+    }
+
+    pub fn active_root(&self) -> Option<&str> {
+        self.state.active_root()
+    }
+
+    pub fn set_active_root(&mut self, name: impl Into<String>) {
+        self.state.set_active_root(name);
+    }
+
     pub fn set_variable(&mut self, variable: String, variable_value: AbstractSyntaxPropertyValue) {
         self.variables.insert(variable, variable_value);
     }
@@ -71,6 +125,10 @@ impl DataContext {
     ) -> Result<AbstractSyntaxProperty, DataContextError> {
         match property.value() {
             AbstractSyntaxPropertyValue::VariablePath(_) |
+            AbstractSyntaxPropertyValue::InterpolatedText(_) |
+            AbstractSyntaxPropertyValue::Selector(_) |
+            AbstractSyntaxPropertyValue::Array(_) |
+            AbstractSyntaxPropertyValue::Expression(_) |
             AbstractSyntaxPropertyValue::Function(_) => {
                 Ok(property.set_value(self.replace_variable_data_in_value(property.value())?))
             },
@@ -87,6 +145,14 @@ impl DataContext {
                 Ok(AbstractSyntaxPropertyValue::Function(self.replace_variable_data_in_function(function)?)),
             AbstractSyntaxPropertyValue::VariablePath(variable) => 
                 Ok(self.get_variable_value(variable)?),
+            AbstractSyntaxPropertyValue::Selector(function) =>
+                self.run_selector_function(function),
+            AbstractSyntaxPropertyValue::InterpolatedText(text) =>
+                Ok(AbstractSyntaxPropertyValue::String(self.replace_variable_data_in_text(text)?)),
+            AbstractSyntaxPropertyValue::Array(values) =>
+                Ok(AbstractSyntaxPropertyValue::Array(self.replace_variable_data_in_array(values)?)),
+            AbstractSyntaxPropertyValue::Expression(expression) =>
+                self.evaluate_expression(expression),
             _ =>
                 Ok(property_value.clone())
         }
@@ -104,6 +170,61 @@ impl DataContext {
         return Ok(function.set_arguments(resolved_arguments));
     }
 
+    pub fn replace_variable_data_in_array(
+        &mut self,
+        values: &[AbstractSyntaxPropertyValue]
+    ) -> Result<Vec<AbstractSyntaxPropertyValue>, DataContextError> {
+        let mut resolved_values = vec!();
+        for value in values {
+            resolved_values.push(self.replace_variable_data_in_value(value)?);
+        }
+        Ok(resolved_values)
+    }
+
+    pub fn evaluate_expression(&mut self, expression: &Expression) -> Result<AbstractSyntaxPropertyValue, DataContextError> {
+        match expression {
+            Expression::Value(value) => self.replace_variable_data_in_value(value),
+            Expression::Unary(operator, operand) => {
+                let operand_value = self.evaluate_expression(operand)?;
+                Ok(apply_unary_operator(*operator, &operand_value)?)
+            },
+            Expression::Binary(left, CodeOperator::And, right) => {
+                if !self.evaluate_expression(left)?.get_bool_value()? {
+                    return Ok(AbstractSyntaxPropertyValue::Bool(false));
+                }
+                Ok(AbstractSyntaxPropertyValue::Bool(self.evaluate_expression(right)?.get_bool_value()?))
+            },
+            Expression::Binary(left, CodeOperator::Or, right) => {
+                if self.evaluate_expression(left)?.get_bool_value()? {
+                    return Ok(AbstractSyntaxPropertyValue::Bool(true));
+                }
+                Ok(AbstractSyntaxPropertyValue::Bool(self.evaluate_expression(right)?.get_bool_value()?))
+            },
+            Expression::Binary(left, operator, right) => {
+                let left_value = self.evaluate_expression(left)?;
+                let right_value = self.evaluate_expression(right)?;
+                Ok(apply_binary_operator(*operator, &left_value, &right_value)?)
+            },
+            Expression::Conditional(condition, when_true, when_false) => {
+                if self.evaluate_expression(condition)?.get_bool_value()? {
+                    return self.evaluate_expression(when_true);
+                }
+                self.evaluate_expression(when_false)
+            }
+        }
+    }
+
+    fn replace_variable_data_in_text(&self, text: &InterpolatedText) -> Result<String, DataContextError> {
+        let mut resolved_text = String::new();
+        for part in text.parts() {
+            match part {
+                InterpolatedTextPart::Text(value) => resolved_text.push_str(value),
+                InterpolatedTextPart::Variable(variable) => resolved_text.push_str(&self.get_variable_value(variable)?.get_display_value()?)
+            }
+        }
+        Ok(resolved_text)
+    }
+
     fn get_variable_value(&self, variable: &VariablePath) -> Result<AbstractSyntaxPropertyValue, DataContextError> {
         if let Some(variable_value) = self.variables.get(variable.variable_part()) {
             match variable_value {
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+type CustomControlRenderer = Box<dyn Fn(&CustomControlProperties, &mut egui::Ui)>;
+
+pub struct CustomControl {
+    name: String,
+    schema: ControlSchema,
+    render: CustomControlRenderer
+}
+
+impl CustomControl {
+    pub fn new(
+        name: impl Into<String>,
+        schema: ControlSchema,
+        render: impl Fn(&CustomControlProperties, &mut egui::Ui) + 'static
+    ) -> Self {
+        let name = name.into();
+        Self {
+            schema: schema.with_control_name(name.clone()),
+            name,
+            render: Box::new(render)
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn schema(&self) -> &ControlSchema {
+        &self.schema
+    }
+
+    pub fn render(&self, properties: &CustomControlProperties, ui: &mut egui::Ui) {
+        (self.render)(properties, ui)
+    }
+}
+
+pub struct CustomControlProperties(Vec<AbstractSyntaxProperty>);
+
+impl CustomControlProperties {
+    pub fn new(properties: Vec<AbstractSyntaxProperty>) -> Self {
+        Self(properties)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AbstractSyntaxPropertyValue> {
+        self.0.iter()
+            .rev()
+            .find(|property| matches!(property.property_type(), AbstractSyntaxPropertyType::Custom(property_name) if property_name == name))
+            .map(|property| property.value())
+    }
+}
+
+#[derive(Default)]
+pub struct RegisteredControls {
+    controls: HashMap<String, CustomControl>
+}
+
+impl RegisteredControls {
+    pub fn register_control(&mut self, control: CustomControl) {
+        self.controls.insert(control.name().to_string(), control);
+    }
+
+    pub fn get_control(&self, control_name: &str) -> Option<&CustomControl> {
+        self.controls.get(control_name)
+    }
+
+    pub fn control_names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.controls.keys().map(String::as_str)
+    }
+}
\ No newline at end of file
@@ -3,11 +3,16 @@ mod state;
 mod containers;
 mod actions;
 mod selectors;
+mod controls;
 mod arrays;
+mod roots;
+mod tests;
 
 pub use context::*;
 pub use state::*;
 pub use containers::*;
 pub use actions::*;
 pub use selectors::*;
-pub use arrays::*;
\ No newline at end of file
+pub use controls::*;
+pub use arrays::*;
+pub use roots::*;
\ No newline at end of file
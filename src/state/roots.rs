@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+pub const DEFAULT_ROOT_NAME: &str = "main";
+pub const SWITCH_ROOT_ACTION: &str = "ruxy::switch_root";
+
+pub struct SwitchRootActionContainer;
+
+impl ActionContainer for SwitchRootActionContainer {
+    fn function_name(&self) -> &str {
+        SWITCH_ROOT_ACTION
+    }
+
+    fn run(&self, state: &mut State, arguments: &Vec<AbstractSyntaxPropertyValue>) -> Result<(), ContainerRunError> {
+        if arguments.len() != 1 {
+            return Err(ContainerRunError::IncorrectAmountOfArgumentsPassed);
+        }
+        state.set_active_root(arguments[0].get_string_value()?);
+        Ok(())
+    }
+}
\ No newline at end of file
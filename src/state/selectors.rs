@@ -23,4 +23,8 @@ impl RegisteredSelectors {
     pub fn get_selector_container(&self, function_name: &str) -> Option<&Box<dyn SelectorContainer>> {
         self.selectors.get(function_name)
     }
+
+    pub fn function_names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.selectors.keys().map(String::as_str)
+    }
 }
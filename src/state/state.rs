@@ -2,10 +2,19 @@ use crate::prelude::*;
 
 #[derive(Default)]
 pub struct State {
-    items: HashMap<usize, Box<dyn std::any::Any>>
+    items: HashMap<usize, Box<dyn std::any::Any>>,
+    active_root: Option<String>
 }
 
 impl State {
+    pub fn active_root(&self) -> Option<&str> {
+        self.active_root.as_deref()
+    }
+
+    pub fn set_active_root(&mut self, name: impl Into<String>) {
+        self.active_root = Some(name.into());
+    }
+
     pub fn process<T:std::any::Any + Default>(&mut self, id: usize, processor: Box<dyn FnOnce(&T) -> T>) {
         let processed_state = if let Some(item) = self.get(id) {
             processor(item.downcast_ref::<T>().unwrap())
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+fn create_meter_control() -> CustomControl {
+    let schema = ControlSchema::new(AbstractSyntaxControlType::Custom)
+        .with_custom_property("level", PropertyValueType::Float)
+        .with_custom_property("inverted", PropertyValueType::Bool)
+        .with_custom_default("label", PropertyValueType::Text, AbstractSyntaxPropertyValue::String("meter".to_string()));
+    CustomControl::new("meter", schema, |_, _| {})
+}
+
+fn build(source: &str) -> (AbstractSyntax, DataContext, Vec<String>) {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", source);
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    context.controls_mut().register_control(create_meter_control());
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+    let diagnostics = ast.diagnostics().map(|diagnostic| diagnostic.message().to_string()).collect();
+    (ast, context, diagnostics)
+}
+
+fn meter_properties(ast: &AbstractSyntax, context: &DataContext) -> CustomControlProperties {
+    let graph = ast.graph();
+    let mut meter = graph.get_children(graph.get_root().unwrap())[0];
+    while meter.node_type() == AbstractSyntaxControlType::Container {
+        meter = graph.get_children(meter)[0];
+    }
+    assert_eq!(AbstractSyntaxControlType::Custom, meter.node_type());
+    let control = context.controls().get_control("meter").unwrap();
+    CustomControlProperties::new(control.schema().with_defaults(meter.properties()))
+}
+
+#[test]
+fn custom_control_resolves_properties() {
+    let (ast, context, diagnostics) = build("<root><let $level={0.5}><meter level=$level inverted /></let></root>");
+
+    assert!(diagnostics.is_empty());
+    let properties = meter_properties(&ast, &context);
+    assert_eq!(0.5, properties.get("level").unwrap().get_float_value().unwrap());
+    assert!(properties.get("inverted").unwrap().get_bool_value().unwrap());
+    assert_eq!("meter", properties.get("label").unwrap().get_string_value().unwrap());
+}
+
+#[test]
+fn custom_control_text_uses_text_property() {
+    let schema = ControlSchema::new(AbstractSyntaxControlType::Custom)
+        .with_custom_property("text", PropertyValueType::Text);
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root><chip>online</chip></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    context.controls_mut().register_control(CustomControl::new("chip", schema, |_, _| {}));
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+
+    let graph = ast.graph();
+    let chip = graph.get_children(graph.get_root().unwrap())[0];
+    let control = context.controls().get_control("chip").unwrap();
+    let properties = CustomControlProperties::new(control.schema().with_defaults(chip.properties()));
+    assert_eq!("online", properties.get("text").unwrap().get_string_value().unwrap());
+}
+
+#[test]
+fn custom_control_validates_properties() {
+    let (ast, context, diagnostics) = build("<root><meter level=\"high\" levl=0.5 /></root>");
+
+    assert_eq!(vec!(
+        "property \"level\" expects a Float value".to_string(),
+        "unknown property \"levl\", did you mean \"level\"?".to_string()
+    ), diagnostics);
+    assert!(meter_properties(&ast, &context).get("level").is_none());
+}
+
+#[test]
+fn custom_control_validates_flags() {
+    let (ast, context, diagnostics) = build("<root><meter invertd level /></root>");
+
+    assert_eq!(vec!(
+        "unknown property \"invertd\", did you mean \"inverted\"?".to_string(),
+        "property \"level\" expects a Float value".to_string()
+    ), diagnostics);
+    let properties = meter_properties(&ast, &context);
+    assert!(properties.get("inverted").is_none());
+    assert!(properties.get("level").is_none());
+}
+
+#[test]
+fn unknown_control_suggests_custom_control() {
+    let (_, _, diagnostics) = build("<root><metre level=0.5 /></root>");
+
+    assert_eq!(vec!("unknown control \"metre\", did you mean \"meter\"?".to_string()), diagnostics);
+}
+
+#[test]
+fn standard_controls_take_precedence_over_custom_controls() {
+    let file_system = MemoryFileSystem::default().with_source("app.rux", "<root><label text=\"a\" /></root>");
+    let mut source_files = file_system.source_files();
+    let mut context = create_data_context();
+    context.controls_mut().register_control(CustomControl::new("label", ControlSchema::new(AbstractSyntaxControlType::Custom), |_, _| {}));
+    let mut ast = AbstractSyntax::default();
+    let changes = source_files.process().unwrap();
+    ast.build(&changes, &mut source_files, &mut context);
+
+    let graph = ast.graph();
+    assert_eq!(AbstractSyntaxControlType::Label, graph.get_children(graph.get_root().unwrap())[0].node_type());
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod custom_controls;
\ No newline at end of file